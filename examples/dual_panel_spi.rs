@@ -0,0 +1,147 @@
+//! Drive two 128x64 panels side by side as one 256x64 canvas, sharing a single SPI peripheral.
+//!
+//! Each panel gets its own DC and CS pin. The SPI peripheral is shared between both drivers with a
+//! small `RefCell`-based proxy. Only one driver talks on the bus at a time because each flush
+//! holds the CS line of its own panel low for the duration of the transfer.
+//!
+//! This example is for the STM32F103 "Blue Pill" board using SPI1.
+//!
+//! Wiring connections are as follows
+//!
+//! ```
+//!      Display -> Blue Pill
+//!          GND -> GND (both panels)
+//!          VCC -> 3.3V or 5V (both panels, check your module's input voltage)
+//!          SCK -> PA5 (both panels)
+//!         MOSI -> PA7 (both panels)
+//!   Left DC    -> PA2
+//!   Left CS    -> PA1
+//!   Right DC   -> PA4
+//!   Right CS   -> PA3
+//! ```
+//!
+//! Run on a Blue Pill with `cargo run --example dual_panel_spi`.
+
+#![no_std]
+#![no_main]
+
+extern crate cortex_m;
+extern crate cortex_m_rt as rt;
+extern crate panic_semihosting;
+extern crate stm32f1xx_hal as hal;
+
+use core::cell::RefCell;
+use cortex_m_rt::ExceptionFrame;
+use cortex_m_rt::{entry, exception};
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Circle, Line};
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::spi;
+use hal::prelude::*;
+use hal::spi::Spi;
+use hal::stm32;
+use sh1106::prelude::*;
+use sh1106::Builder;
+
+/// Shares one SPI peripheral between several drivers.
+struct SpiProxy<'a, SPI>(&'a RefCell<SPI>);
+
+impl<'a, SPI, E> Write<u8> for SpiProxy<'a, SPI>
+where
+    SPI: Write<u8, Error = E>,
+{
+    type Error = E;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), E> {
+        self.0.borrow_mut().write(words)
+    }
+}
+
+impl<'a, SPI, E> Transfer<u8> for SpiProxy<'a, SPI>
+where
+    SPI: Transfer<u8, Error = E>,
+{
+    type Error = E;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], E> {
+        self.0.borrow_mut().transfer(words)
+    }
+}
+
+#[entry]
+fn main() -> ! {
+    let dp = stm32::Peripherals::take().unwrap();
+
+    let mut flash = dp.FLASH.constrain();
+    let mut rcc = dp.RCC.constrain();
+
+    let clocks = rcc.cfgr.freeze(&mut flash.acr);
+
+    let mut afio = dp.AFIO.constrain(&mut rcc.apb2);
+
+    let mut gpioa = dp.GPIOA.split(&mut rcc.apb2);
+
+    let sck = gpioa.pa5.into_alternate_push_pull(&mut gpioa.crl);
+    let miso = gpioa.pa6.into_floating_input(&mut gpioa.crl);
+    let mosi = gpioa.pa7.into_alternate_push_pull(&mut gpioa.crl);
+    let left_dc = gpioa.pa2.into_push_pull_output(&mut gpioa.crl);
+    let left_cs = gpioa.pa1.into_push_pull_output(&mut gpioa.crl);
+    let right_dc = gpioa.pa4.into_push_pull_output(&mut gpioa.crl);
+    let right_cs = gpioa.pa3.into_push_pull_output(&mut gpioa.crl);
+
+    let spi = RefCell::new(Spi::spi1(
+        dp.SPI1,
+        (sck, miso, mosi),
+        &mut afio.mapr,
+        spi::MODE_0,
+        400.khz(),
+        clocks,
+        &mut rcc.apb2,
+    ));
+
+    let mut left: GraphicsMode<_> = Builder::new()
+        .with_spi_cs(left_cs)
+        .connect_spi(SpiProxy(&spi), left_dc)
+        .into();
+    let mut right: GraphicsMode<_> = Builder::new()
+        .with_spi_cs(right_cs)
+        .connect_spi(SpiProxy(&spi), right_dc)
+        .into();
+
+    left.init().unwrap();
+    right.init().unwrap();
+
+    let (panel_width, _) = left.get_dimensions();
+    let panel_width = panel_width as i32;
+
+    // Draw onto a logical 256x64 canvas, routing each pixel to the panel it falls on
+    let canvas = Line::new(Point::new(0, 0), Point::new(255, 63))
+        .stroke(Some(BinaryColor::On))
+        .into_iter()
+        .chain(
+            Circle::new(Point::new(128, 32), 24)
+                .stroke(Some(BinaryColor::On))
+                .into_iter(),
+        );
+
+    for Pixel(point, color) in canvas {
+        if point.x < panel_width {
+            left.draw(core::iter::once(Pixel(point, color)));
+        } else {
+            let point = Point::new(point.x - panel_width, point.y);
+            right.draw(core::iter::once(Pixel(point, color)));
+        }
+    }
+
+    // Flush both halves back to back so they update together
+    left.flush().unwrap();
+    right.flush().unwrap();
+
+    loop {}
+}
+
+#[exception]
+fn HardFault(ef: &ExceptionFrame) -> ! {
+    panic!("{:#?}", ef);
+}
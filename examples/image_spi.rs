@@ -32,7 +32,6 @@ extern crate stm32f1xx_hal as hal;
 
 use cortex_m_rt::ExceptionFrame;
 use cortex_m_rt::{entry, exception};
-use embedded_graphics::{image::Image, pixelcolor::BinaryColor, prelude::*};
 use embedded_hal::spi;
 use hal::prelude::*;
 use hal::spi::Spi;
@@ -74,10 +73,7 @@ fn main() -> ! {
     disp.init().unwrap();
     disp.flush().unwrap();
 
-    let im: Image<BinaryColor> =
-        Image::new(include_bytes!("./rust.raw"), 64, 64).translate(Point::new(32, 0));
-
-    disp.draw(im.into_iter());
+    disp.draw_raw_image(include_bytes!("./rust.raw"), 64, 32, 0);
 
     disp.flush().unwrap();
 
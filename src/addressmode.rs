@@ -0,0 +1,17 @@
+//! Display addressing mode
+
+/// How writes to display RAM advance through the framebuffer
+///
+/// The SH1106 is always page addressed in hardware: each data byte covers a column of 8 vertical
+/// pixels in the current page, and the column pointer advances after every byte. The modes below
+/// only change how the driver moves between pages when data is sent with
+/// [`DisplayProperties::draw`](../properties/struct.DisplayProperties.html#method.draw).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AddressMode {
+    /// When the end of the draw area is reached, the driver moves on to the start of the next
+    /// page. A whole frame can be sent in a single `draw` call. This is the default.
+    Column,
+    /// When the end of the draw area is reached, the driver wraps back to the start of the same
+    /// page. The page to write to must be selected explicitly with `set_draw_area`.
+    Page,
+}
@@ -0,0 +1,78 @@
+//! Simple keyframe timeline for transitions
+//!
+//! A [`Timeline`](struct.Timeline.html) calls a closure once per frame with the progress through
+//! the animation from `0.0` to `1.0`, flushing the display after each frame and waiting between
+//! frames with the given delay provider. No allocation is performed.
+//!
+//! ```rust,ignore
+//! let mut disp: GraphicsMode<_> = Builder::new().connect_i2c(i2c).into();
+//! disp.init().unwrap();
+//!
+//! // Slide a 16px wide bar in from the left over half a second
+//! Timeline::new(500, 20)
+//!     .play(&mut disp, &mut delay, |disp, progress| {
+//!         disp.clear();
+//!         let x = (progress * 112.0) as u32;
+//!         for y in 24..40 {
+//!             disp.draw_pixel_run(x, y, 16, true);
+//!         }
+//!     })
+//!     .unwrap();
+//! ```
+
+use hal::blocking::delay::DelayMs;
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+/// Animation timeline
+#[derive(Clone, Copy, Debug)]
+pub struct Timeline {
+    duration_ms: u32,
+    frame_ms: u16,
+}
+
+impl Timeline {
+    /// Create a new timeline lasting `duration_ms` milliseconds, drawing a frame every `frame_ms`
+    /// milliseconds
+    pub fn new(duration_ms: u32, frame_ms: u16) -> Self {
+        Self {
+            duration_ms,
+            frame_ms,
+        }
+    }
+
+    /// Number of frame intervals in the timeline. The closure passed to `play` is called one more
+    /// time than this so both `0.0` and `1.0` are drawn.
+    pub fn frames(&self) -> u32 {
+        (self.duration_ms / u32::from(self.frame_ms.max(1))).max(1)
+    }
+
+    /// Run the animation. `f` is called with the display and the current progress, after which
+    /// the display is flushed. Returns early if a flush fails.
+    pub fn play<DI, BUF, DELAY, F>(
+        &self,
+        display: &mut GraphicsMode<DI, BUF>,
+        delay: &mut DELAY,
+        mut f: F,
+    ) -> Result<(), DI::Error>
+    where
+        DI: DisplayInterface,
+        BUF: AsRef<[u8]> + AsMut<[u8]>,
+        DELAY: DelayMs<u16>,
+        F: FnMut(&mut GraphicsMode<DI, BUF>, f32),
+    {
+        let frames = self.frames();
+
+        for frame in 0..=frames {
+            f(display, frame as f32 / frames as f32);
+            display.flush()?;
+
+            if frame < frames {
+                delay.delay_ms(self.frame_ms);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+//! Bit order of packed bitmap data
+
+/// Which bit of each byte holds the first pixel when bitmaps are packed 8 pixels to a byte, as
+/// taken by [`draw_bitmap`](../mode/graphics/struct.GraphicsMode.html#method.draw_bitmap) and
+/// [`draw_splash`](../mode/graphics/struct.GraphicsMode.html#method.draw_splash). Tools disagree
+/// on this, and the wrong order shows up as a scrambled image with every group of 8 pixels
+/// mirrored.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BitOrder {
+    /// The most significant bit holds the first pixel. ImageMagick's
+    /// `convert image.png -depth 1 gray:image.raw` produces this order.
+    #[default]
+    MsbFirst,
+    /// The least significant bit holds the first pixel. XBM files, e.g. from
+    /// `convert image.png xbm:image.xbm`, and the SH1106's own display RAM use this order.
+    LsbFirst,
+}
+
+impl BitOrder {
+    /// Get the mask selecting pixel `index` (0 to 7) within a byte
+    pub const fn mask(self, index: usize) -> u8 {
+        match self {
+            BitOrder::MsbFirst => 0x80 >> (index % 8),
+            BitOrder::LsbFirst => 1 << (index % 8),
+        }
+    }
+
+    /// Convert a byte in this order to LSB first order
+    pub const fn to_lsb_first(self, byte: u8) -> u8 {
+        match self {
+            BitOrder::MsbFirst => byte.reverse_bits(),
+            BitOrder::LsbFirst => byte,
+        }
+    }
+}
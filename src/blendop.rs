@@ -0,0 +1,15 @@
+//! Buffer blending operations
+
+/// How [`GraphicsMode::compose`](../mode/graphics/struct.GraphicsMode.html#method.compose)
+/// combines an overlay with the display buffer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BlendOp {
+    /// Pixels are on if they are on in the buffer or the overlay
+    Or,
+    /// Pixels are on only if they are on in both the buffer and the overlay
+    And,
+    /// Pixels in the buffer are inverted wherever the overlay is on
+    Xor,
+    /// The overlay replaces the buffer contents
+    Replace,
+}
@@ -0,0 +1,209 @@
+//! Bitmap blitting for [`GraphicsMode`](../mode/graphics/struct.GraphicsMode.html)
+//!
+//! Bitmaps are 1 bit per pixel, row-major. Each row starts on a new byte, so a row of a bitmap
+//! `width` pixels wide takes `(width + 7) / 8` bytes. Glyphs and sprites are most significant bit
+//! first, the format produced by ImageMagick's `convert image.png -depth 1 gray:image.raw`, while
+//! the bitmap methods take a [`BitOrder`](../bitorder/enum.BitOrder.html) to also accept least
+//! significant bit first data such as XBM files.
+//!
+//! All methods take the current display rotation into account and ignore pixels outside the
+//! display.
+
+use crate::bitorder::BitOrder;
+use crate::displayrotation::DisplayRotation;
+use crate::interface::DisplayInterface;
+use crate::mirror::Mirror;
+use crate::mode::graphics::GraphicsMode;
+
+/// Whether the pixel at `col, row` is set in a row-major bitmap with the given bit order
+fn bit_at(data: &[u8], stride: usize, col: usize, row: usize, order: BitOrder) -> bool {
+    data.get(row * stride + col / 8)
+        .map(|byte| byte & order.mask(col) != 0)
+        .unwrap_or(false)
+}
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Draw a bitmap with its top left corner at `x, y`. Set bits in the bitmap turn pixels on and
+    /// cleared bits turn them off, replacing what was there before. `order` says which bit of each
+    /// byte holds the leftmost of its 8 pixels.
+    pub fn draw_bitmap(
+        &mut self,
+        data: &[u8],
+        width: u8,
+        height: u8,
+        x: u32,
+        y: u32,
+        order: BitOrder,
+    ) {
+        let stride = (width as usize).div_ceil(8);
+
+        for row in 0..height as usize {
+            for col in 0..width as usize {
+                let on = bit_at(data, stride, col, row, order);
+
+                self.set_pixel(
+                    x.saturating_add(col as u32),
+                    y.saturating_add(row as u32),
+                    on as u8,
+                );
+            }
+        }
+    }
+
+    /// Draw a bitmap like [`draw_bitmap`](#method.draw_bitmap), converting it to the buffer
+    /// layout for the current rotation as it is copied. When the bitmap is aligned to the buffer's
+    /// byte boundaries (`y` a multiple of 8 when rotated by 0 or 180 degrees, `x` a multiple of 8
+    /// when rotated by 90 or 270 degrees) whole buffer bytes are written at a time, which is much
+    /// faster for static images redrawn every frame. Otherwise, or when the display is mirrored,
+    /// this falls back to `draw_bitmap`.
+    pub fn draw_bitmap_rotated(
+        &mut self,
+        data: &[u8],
+        width: u8,
+        height: u8,
+        x: u32,
+        y: u32,
+        order: BitOrder,
+    ) {
+        let (display_width, display_height) = self.properties().get_size().dimensions();
+        let display_width = display_width as usize;
+        let pages = display_height as usize / 8;
+        let stride = (width as usize).div_ceil(8);
+        let (width, height) = (width as usize, height as usize);
+
+        let unmirrored = self.get_mirror() == Mirror::None;
+
+        match self.properties().get_rotation() {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180
+                if unmirrored && y.is_multiple_of(8) =>
+            {
+                // Every 8 bitmap rows make up one page of the buffer
+                let buffer = self.buffer_mut();
+                let first_page = y as usize / 8;
+
+                for block in 0..height.div_ceil(8) {
+                    let page = first_page + block;
+
+                    if page >= pages {
+                        break;
+                    }
+
+                    let rows = (height - block * 8).min(8);
+                    let mask = ((1u16 << rows) - 1) as u8;
+
+                    for col in 0..width {
+                        let column = (x as usize).saturating_add(col);
+
+                        if column >= display_width {
+                            break;
+                        }
+
+                        let bits = (0..rows)
+                            .filter(|bit| bit_at(data, stride, col, block * 8 + bit, order))
+                            .fold(0u8, |bits, bit| bits | (1 << bit));
+
+                        let byte = &mut buffer[page * display_width + column];
+                        *byte = (*byte & !mask) | bits;
+                    }
+                }
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270
+                if unmirrored && x.is_multiple_of(8) =>
+            {
+                // Each bitmap byte covers the same 8 pixels as one buffer byte
+                let buffer = self.buffer_mut();
+                let first_page = x as usize / 8;
+
+                for row in 0..height {
+                    let column = (y as usize).saturating_add(row);
+
+                    if column >= display_width {
+                        break;
+                    }
+
+                    for byte_idx in 0..stride {
+                        let page = first_page + byte_idx;
+
+                        if page >= pages {
+                            break;
+                        }
+
+                        let valid = (width - byte_idx * 8).min(8);
+                        let mask = ((1u16 << valid) - 1) as u8;
+                        let bits = data
+                            .get(row * stride + byte_idx)
+                            .map(|byte| order.to_lsb_first(*byte) & mask)
+                            .unwrap_or(0);
+
+                        let byte = &mut buffer[page * display_width + column];
+                        *byte = (*byte & !mask) | bits;
+                    }
+                }
+            }
+            _ => self.draw_bitmap(data, width as u8, height as u8, x, y, order),
+        }
+    }
+
+    /// Draw a small glyph or icon with its top left corner at `x, y`. Pixels that are set in the
+    /// glyph are turned on or off according to `on`, while cleared bits leave the display buffer
+    /// untouched.
+    pub fn draw_glyph(&mut self, glyph: &[u8], width: u8, height: u8, x: u32, y: u32, on: bool) {
+        let stride = (width as usize).div_ceil(8);
+
+        for row in 0..height as usize {
+            for col in 0..width as usize {
+                if bit_at(glyph, stride, col, row, BitOrder::MsbFirst) {
+                    self.set_pixel(
+                        x.saturating_add(col as u32),
+                        y.saturating_add(row as u32),
+                        on as u8,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Invert the pixels under the set bits of a sprite bitmap with its top left corner at `x, y`,
+    /// leaving pixels under cleared bits alone. The height follows from the length of `data`.
+    /// Blitting the same sprite at the same place again restores what was there, so a sprite can
+    /// be moved by erasing it at its old position and blitting it at the new one.
+    pub fn xor_blit(&mut self, data: &[u8], width: u8, x: u32, y: u32) {
+        if width == 0 {
+            return;
+        }
+
+        let stride = (width as usize).div_ceil(8);
+
+        for row in 0..data.len() / stride {
+            for col in 0..width as usize {
+                if bit_at(data, stride, col, row, BitOrder::MsbFirst) {
+                    self.toggle_pixel(x.saturating_add(col as u32), y.saturating_add(row as u32));
+                }
+            }
+        }
+    }
+
+    /// Draw a grid of square modules, such as a QR code, with its top left corner at `x, y`.
+    /// `modules` holds the grid row by row, `cols` modules per row, and `true` turns a module's
+    /// pixels on. Each module is drawn as a `scale` by `scale` square. Off modules are drawn too,
+    /// but the quiet zone around a code is up to the caller. A trailing partial row is ignored.
+    pub fn draw_module_grid(&mut self, modules: &[bool], cols: u32, x: u32, y: u32, scale: u32) {
+        if cols == 0 || scale == 0 {
+            return;
+        }
+
+        for (row, line) in modules.chunks_exact(cols as usize).enumerate() {
+            let module_y = y.saturating_add((row as u32).saturating_mul(scale));
+
+            for (col, on) in line.iter().enumerate() {
+                let module_x = x.saturating_add((col as u32).saturating_mul(scale));
+
+                self.fill_area(module_x, module_y, scale, scale, *on);
+            }
+        }
+    }
+}
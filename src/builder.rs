@@ -39,24 +39,113 @@
 //!
 //! let display: GraphicsMode<_> = Builder::new().connect_spi(spi, dc).into();
 //! ```
+//!
+//! # SPI pins
+//!
+//! Over SPI the display needs a data/command (DC) pin on top of the SPI clock and data lines. DC
+//! tells the controller whether each byte is a command (low) or display data (high), so it's
+//! always required and is passed straight to
+//! [`connect_spi`](struct.Builder.html#method.connect_spi). Chip select (CS) and reset (RES) are
+//! optional and set beforehand with
+//! [`with_spi_cs`](struct.Builder.html#method.with_spi_cs) and
+//! [`with_spi_rst`](struct.Builder.html#method.with_spi_rst); without them the builder uses
+//! `NoOutputPin` and `NoResetPin` placeholders.
+//!
+//! DC and CS are different signals and must be wired to different GPIOs. Swapping them is an easy
+//! mistake that the type system can't catch, as both are plain output pins: the display then
+//! either ignores every transfer or draws commands as garbage pixels. All pins given to the
+//! builder must have the same error type, which for most HALs is `Infallible`.
+//!
+//! # Hardware access
+//!
+//! Building a driver and converting it into a mode never talks to the display. Nothing is sent
+//! until a method such as `init`, `flush` or `set_contrast` is called, and drawing into a
+//! `GraphicsMode` buffer doesn't touch the bus either. Unless clear on drop is enabled, dropping
+//! the driver doesn't talk to the display. This makes it safe to construct displays in tests
+//! with an interface that isn't backed by hardware:
+//!
+//! ```rust
+//! # use sh1106::interface::DisplayInterface;
+//! # use sh1106::prelude::*;
+//! # use sh1106::Builder;
+//! #
+//! struct NoHardware;
+//!
+//! impl DisplayInterface for NoHardware {
+//!     type Error = ();
+//!
+//!     fn init(&mut self) -> Result<(), ()> {
+//!         panic!("init() touched the bus")
+//!     }
+//!
+//!     fn send_commands(&mut self, _cmds: &[u8]) -> Result<(), ()> {
+//!         panic!("send_commands() touched the bus")
+//!     }
+//!
+//!     fn send_data(&mut self, _buf: &[u8]) -> Result<(), ()> {
+//!         panic!("send_data() touched the bus")
+//!     }
+//! }
+//!
+//! let mut display: GraphicsMode<_> = Builder::new()
+//!     .with_rotation(DisplayRotation::Rotate90)
+//!     .connect(NoHardware)
+//!     .into();
+//!
+//! display.set_pixel(10, 20, 1);
+//! display.clear();
+//! assert!(display.is_dirty());
+//! ```
 
 use hal;
 use hal::digital::v2::OutputPin;
 
+use crate::addressmode::AddressMode;
 use crate::displayrotation::DisplayRotation;
 use crate::displaysize::DisplaySize;
-use crate::interface::{I2cInterface, SpiInterface};
+use crate::interface::spi::{NoResetPin, ResetPin};
+use crate::interface::{DisplayInterface, I2cInterface, SpiInterface};
 use crate::mode::displaymode::DisplayMode;
 use crate::mode::raw::RawMode;
 use crate::properties::DisplayProperties;
 
+// Display size used by `Builder::new()`, selected with the `size-*` features. Features are
+// additive, so if more than one is enabled (e.g. with `--all-features`) the first of 132x64, 128x32
+// and 128x64 wins.
+#[cfg(not(any(feature = "size-128x32", feature = "size-132x64")))]
+const DEFAULT_SIZE: DisplaySize = DisplaySize::Display128x64;
+#[cfg(all(feature = "size-128x32", not(feature = "size-132x64")))]
+const DEFAULT_SIZE: DisplaySize = DisplaySize::Display128x32;
+#[cfg(feature = "size-132x64")]
+const DEFAULT_SIZE: DisplaySize = DisplaySize::Display132x64;
+
+/// Configuration errors reported by the fallible builder methods such as
+/// [`Builder::try_connect_i2c`](struct.Builder.html#method.try_connect_i2c)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BuilderError {
+    /// The I2C address isn't a usable 7 bit address. Addresses above `0x7F` don't fit in 7 bits,
+    /// and `0x00`-`0x07` and `0x78`-`0x7F` are reserved by the I2C specification. Passing the
+    /// 8 bit form of the address (e.g. `0x78` instead of `0x3C`) is a common cause.
+    InvalidAddress(u8),
+}
+
 /// Builder struct. Driver options and interface are set using its methods.
 #[derive(Clone, Copy)]
-pub struct Builder<CS = NoOutputPin> {
+pub struct Builder<CS = NoOutputPin, RST = NoResetPin> {
     display_size: DisplaySize,
     rotation: DisplayRotation,
+    address_mode: AddressMode,
     i2c_addr: u8,
+    i2c_control_bytes: (u8, u8),
+    i2c_retries: u8,
+    i2c_max_chunk: usize,
     spi_cs: CS,
+    spi_rst: RST,
+    spi_invert_dc: bool,
+    clear_on_drop: bool,
+    clear_on_init: bool,
+    init_delay_ms: u8,
+    init_nops: u8,
 }
 
 impl Default for Builder {
@@ -67,17 +156,31 @@ impl Default for Builder {
 
 impl Builder {
     /// Create new builder with a default size of 128 x 64 pixels and no rotation.
+    ///
+    /// The default size can be changed at compile time by enabling one of the `size-128x64`,
+    /// `size-128x32` or `size-132x64` features. [`with_size`](#method.with_size) still overrides
+    /// it at runtime.
     pub fn new() -> Builder<NoOutputPin> {
         Builder {
-            display_size: DisplaySize::Display128x64,
+            display_size: DEFAULT_SIZE,
             rotation: DisplayRotation::Rotate0,
+            address_mode: AddressMode::Column,
             i2c_addr: 0x3c,
+            i2c_control_bytes: (0x00, 0x40),
+            i2c_retries: 0,
+            i2c_max_chunk: 128,
             spi_cs: NoOutputPin,
+            spi_rst: NoResetPin,
+            spi_invert_dc: false,
+            clear_on_drop: false,
+            clear_on_init: false,
+            init_delay_ms: 0,
+            init_nops: 0,
         }
     }
 }
 
-impl<CS, PinE> Builder<CS>
+impl<CS, RST, PinE> Builder<CS, RST>
 where
     CS: OutputPin<Error = PinE>,
 {
@@ -95,57 +198,255 @@ where
         Self { i2c_addr, ..self }
     }
 
+    /// Set the I2C control bytes that prefix commands and data respectively. Defaults to `0x00`
+    /// for commands and `0x40` for data. Only change these if your module shows nothing with the
+    /// defaults. Ignored when using SPI interface.
+    pub fn with_i2c_control_bytes(self, command_byte: u8, data_byte: u8) -> Self {
+        Self {
+            i2c_control_bytes: (command_byte, data_byte),
+            ..self
+        }
+    }
+
+    /// Retry failed I2C writes up to `retries` times before giving up. Useful on noisy buses, but
+    /// be aware that retries can mask genuine wiring faults. Defaults to 0. Ignored when using SPI
+    /// interface.
+    pub fn with_i2c_retries(self, retries: u8) -> Self {
+        Self {
+            i2c_retries: retries,
+            ..self
+        }
+    }
+
+    /// Send at most `max_chunk` bytes of display data per I2C write, for HALs that reject long
+    /// writes. Defaults to and is capped at 128. Ignored when using SPI interface.
+    pub fn with_i2c_max_chunk(self, max_chunk: usize) -> Self {
+        Self {
+            i2c_max_chunk: max_chunk,
+            ..self
+        }
+    }
+
     /// Set the rotation of the display to one of four values. Defaults to no rotation.
     pub fn with_rotation(self, rotation: DisplayRotation) -> Self {
         Self { rotation, ..self }
     }
 
+    /// Set the addressing mode the display is initialised in. Defaults to
+    /// [`AddressMode::Column`](../addressmode/enum.AddressMode.html).
+    pub fn with_address_mode(self, address_mode: AddressMode) -> Self {
+        Self {
+            address_mode,
+            ..self
+        }
+    }
+
+    /// Blank the display and turn it off when the driver is dropped. Handy for test fixtures and
+    /// short lived UIs. Any errors that occur during the drop are ignored. Defaults to `false`.
+    pub fn with_clear_on_drop(self, clear_on_drop: bool) -> Self {
+        Self {
+            clear_on_drop,
+            ..self
+        }
+    }
+
+    /// Clear display RAM during `init()` so the panel is blank straight away instead of showing
+    /// random RAM contents until the first flush. Defaults to `false`.
+    ///
+    /// Display RAM holds random contents after a power cycle, but keeps whatever was last drawn
+    /// across a reset pulse, a soft reset of the microcontroller or a `shutdown`, as long as the
+    /// controller stays powered.
+    pub fn with_clear_on_init(self, clear_on_init: bool) -> Self {
+        Self {
+            clear_on_init,
+            ..self
+        }
+    }
+
+    /// Leave display RAM untouched during `init()`, e.g. to keep showing what was drawn before a
+    /// soft reset until the application flushes a new frame. This is the default; use it to undo
+    /// [`with_clear_on_init`](#method.with_clear_on_init) or to make the intent explicit.
+    pub fn with_skip_clear(self) -> Self {
+        Self {
+            clear_on_init: false,
+            ..self
+        }
+    }
+
+    /// Wait `init_delay_ms` milliseconds after enabling the charge pump during initialisation.
+    /// Some modules show nothing if they are written to too soon after power up. The delay needs
+    /// a delay provider, so it only applies when initialising with `init_with_delay` or
+    /// `init_with_reset`. Defaults to 0.
+    pub fn with_init_delay_ms(self, init_delay_ms: u8) -> Self {
+        Self {
+            init_delay_ms,
+            ..self
+        }
+    }
+
+    /// Send `init_nops` NOP commands after every initialisation command, for clone panels that
+    /// end up misconfigured when init commands arrive too quickly. See
+    /// [`DisplayProperties::set_init_nops`](../properties/struct.DisplayProperties.html#method.set_init_nops).
+    /// Defaults to 0.
+    pub fn with_init_nops(self, init_nops: u8) -> Self {
+        Self { init_nops, ..self }
+    }
+
+    /// Drive the SPI data/command pin low for display data and high for commands, for boards that
+    /// wire the DC line inverted. Such boards show garbage with the default polarity. Defaults to
+    /// `false`. Ignored when using I2C interface.
+    pub fn with_spi_inverted_dc(self, invert_dc: bool) -> Self {
+        Self {
+            spi_invert_dc: invert_dc,
+            ..self
+        }
+    }
+
     /// Set the SPI chip select (CS) pin to use. The CS pin is not required for the controller for
     /// function, but can be used if the bus is shared with other devices. If not used, the CS pin
-    /// on the controller should be connected to ground. Ignored when using I2C interface.
-    pub fn with_spi_cs<NEWCS>(self, spi_cs: NEWCS) -> Builder<NEWCS>
+    /// on the controller should be connected to ground. Ignored when using I2C interface. This
+    /// is not the data/command pin, which is passed to [`connect_spi`](#method.connect_spi).
+    ///
+    /// Several displays can share one SPI bus by giving each its own CS and DC pin. See the
+    /// `dual_panel_spi` example for a way to share the SPI peripheral between drivers.
+    pub fn with_spi_cs<NEWCS>(self, spi_cs: NEWCS) -> Builder<NEWCS, RST>
     where
         NEWCS: OutputPin,
     {
         Builder {
             display_size: self.display_size,
             i2c_addr: self.i2c_addr,
+            i2c_control_bytes: self.i2c_control_bytes,
+            i2c_retries: self.i2c_retries,
+            i2c_max_chunk: self.i2c_max_chunk,
             rotation: self.rotation,
+            address_mode: self.address_mode,
             spi_cs,
+            spi_rst: self.spi_rst,
+            spi_invert_dc: self.spi_invert_dc,
+            clear_on_drop: self.clear_on_drop,
+            clear_on_init: self.clear_on_init,
+            init_delay_ms: self.init_delay_ms,
+            init_nops: self.init_nops,
         }
     }
 
+    /// Set the SPI reset (RES) pin to use. When set, the pin is pulsed by
+    /// [`GraphicsMode::init_with_reset`](../mode/graphics/struct.GraphicsMode.html#method.init_with_reset)
+    /// so all display pins can be handed to the driver at once. Ignored when using I2C interface.
+    pub fn with_spi_rst<NEWRST>(self, spi_rst: NEWRST) -> Builder<CS, NEWRST>
+    where
+        NEWRST: OutputPin,
+    {
+        Builder {
+            display_size: self.display_size,
+            i2c_addr: self.i2c_addr,
+            i2c_control_bytes: self.i2c_control_bytes,
+            i2c_retries: self.i2c_retries,
+            i2c_max_chunk: self.i2c_max_chunk,
+            rotation: self.rotation,
+            address_mode: self.address_mode,
+            spi_cs: self.spi_cs,
+            spi_rst,
+            spi_invert_dc: self.spi_invert_dc,
+            clear_on_drop: self.clear_on_drop,
+            clear_on_init: self.clear_on_init,
+            init_delay_ms: self.init_delay_ms,
+            init_nops: self.init_nops,
+        }
+    }
+
+    /// Finish the builder and use an already constructed interface, such as an
+    /// [`AnyInterface`](../interface/any/enum.AnyInterface.html), to communicate with the display.
+    /// The I2C and SPI specific builder options are ignored.
+    pub fn connect<DI>(self, iface: DI) -> DisplayMode<RawMode<DI>>
+    where
+        DI: DisplayInterface,
+    {
+        let mut properties = DisplayProperties::new(iface, self.display_size, self.rotation);
+        properties.set_address_mode(self.address_mode);
+        properties.set_clear_on_drop(self.clear_on_drop);
+        properties.set_clear_on_init(self.clear_on_init);
+        properties.set_init_delay_ms(self.init_delay_ms);
+        properties.set_init_nops(self.init_nops);
+        DisplayMode::<RawMode<DI>>::new(properties)
+    }
+
     /// Finish the builder and use I2C to communicate with the display
     pub fn connect_i2c<I2C, CommE>(self, i2c: I2C) -> DisplayMode<RawMode<I2cInterface<I2C>>>
     where
         I2C: hal::blocking::i2c::Write<Error = CommE>,
     {
-        let properties = DisplayProperties::new(
-            I2cInterface::new(i2c, self.i2c_addr),
-            self.display_size,
-            self.rotation,
-        );
-        DisplayMode::<RawMode<I2cInterface<I2C>>>::new(properties)
+        let (command_byte, data_byte) = self.i2c_control_bytes;
+        let iface = I2cInterface::new(i2c, self.i2c_addr)
+            .with_control_bytes(command_byte, data_byte)
+            .with_retries(self.i2c_retries)
+            .with_max_chunk(self.i2c_max_chunk);
+        self.connect(iface)
+    }
+
+    /// Like [`connect_i2c`](#method.connect_i2c), but check the configuration first and return
+    /// a [`BuilderError`](enum.BuilderError.html) instead of a driver that can never reach the
+    /// display. All display sizes and rotations are supported by every interface, so only the
+    /// address is checked.
+    pub fn try_connect_i2c<I2C, CommE>(
+        self,
+        i2c: I2C,
+    ) -> Result<DisplayMode<RawMode<I2cInterface<I2C>>>, BuilderError>
+    where
+        I2C: hal::blocking::i2c::Write<Error = CommE>,
+    {
+        if !(0x08..0x78).contains(&self.i2c_addr) {
+            return Err(BuilderError::InvalidAddress(self.i2c_addr));
+        }
+
+        Ok(self.connect_i2c(i2c))
     }
 
-    /// Finish the builder and use SPI to communicate with the display
+    /// Finish the builder and use SPI to communicate with the display. `dc` is the required
+    /// data/command pin, labelled DC or sometimes A0 on display modules; it is driven low for
+    /// commands and high for display data, unless swapped with
+    /// [`with_spi_inverted_dc`](#method.with_spi_inverted_dc). The chip select and reset pins,
+    /// if any, come from [`with_spi_cs`](#method.with_spi_cs) and
+    /// [`with_spi_rst`](#method.with_spi_rst). Without a reset pin, the other pins may have any
+    /// error type, e.g. the `Infallible` most HALs use.
+    ///
+    /// ```rust
+    /// # use sh1106::mock::{MockPin, MockSpi};
+    /// # use sh1106::prelude::*;
+    /// # use sh1106::Builder;
+    /// #
+    /// // Pins whose error type is `Infallible`
+    /// let (spi, dc, cs) = (MockSpi, MockPin, MockPin);
+    ///
+    /// let mut display: GraphicsMode<_> = Builder::new().with_spi_cs(cs).connect_spi(spi, dc).into();
+    /// assert!(display.init().is_ok());
+    /// ```
     pub fn connect_spi<SPI, DC, CommE>(
         self,
         spi: SPI,
         dc: DC,
-    ) -> DisplayMode<RawMode<SpiInterface<SPI, DC, CS>>>
+    ) -> DisplayMode<RawMode<SpiInterface<SPI, DC, CS, RST>>>
     where
         SPI: hal::blocking::spi::Transfer<u8, Error = CommE>
             + hal::blocking::spi::Write<u8, Error = CommE>,
         DC: OutputPin<Error = PinE>,
         CS: OutputPin<Error = PinE>,
+        RST: ResetPin<PinE>,
     {
-        let properties = DisplayProperties::new(
-            SpiInterface::new(spi, dc, self.spi_cs),
+        let mut properties = DisplayProperties::new(
+            SpiInterface::new(spi, dc, self.spi_cs)
+                .with_reset_pin(self.spi_rst)
+                .with_inverted_dc(self.spi_invert_dc),
             self.display_size,
             self.rotation,
         );
-        DisplayMode::<RawMode<SpiInterface<SPI, DC, CS>>>::new(properties)
+        properties.set_address_mode(self.address_mode);
+        properties.set_clear_on_drop(self.clear_on_drop);
+        properties.set_clear_on_init(self.clear_on_init);
+        properties.set_init_delay_ms(self.init_delay_ms);
+        properties.set_init_nops(self.init_nops);
+        DisplayMode::<RawMode<SpiInterface<SPI, DC, CS, RST>>>::new(properties)
     }
 }
 
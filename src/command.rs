@@ -23,6 +23,10 @@ pub enum Command {
     PageAddress(Page),
     /// Set display start line from 0-63
     StartLine(u8),
+    /// Set display start line from 0-127 (SH1107 only)
+    StartLineExt(u8),
+    /// Use vertical (true) or page (false) addressing (SH1107 only)
+    VerticalAddressing(bool),
     /// Reverse columns from 127-0
     SegmentRemap(bool),
     /// Set multipex ratio from 15-63 (MUX-1)
@@ -33,18 +37,24 @@ pub enum Command {
     DisplayOffset(u8),
     /// Setup com hardware configuration
     /// First value indicates sequential (false) or alternative (true)
-    /// pin configuration.
-    ComPinConfig(bool),
+    /// pin configuration. Second value swaps the left and right COM pins.
+    ComPinConfig(bool, bool),
     /// Set up display clock.
     /// First value is oscillator frequency, increasing with higher value
     /// Second value is divide ratio - 1
     DisplayClockDiv(u8, u8),
-    /// Set up phase 1 and 2 of precharge period. each value is from 0-63
+    /// Set up phase 1 (pre-charge) and phase 2 (discharge) of the precharge period, in display
+    /// clocks. Each value is from 1-15
     PreChargePeriod(u8, u8),
-    /// Set Vcomh Deselect level
-    VcomhDeselect(VcomhLevel),
+    /// Set Vcomh Deselect level. `0x00` is 0.43 * Vref, rising in steps to 1.0 * Vref at `0x40`
+    /// and above
+    VcomhDeselect(u8),
     /// NOOP
     Noop,
+    /// Enter read-modify-write mode. Reads no longer advance the column address
+    ReadModifyWrite,
+    /// Leave read-modify-write mode, restoring the column address from before it was entered
+    End,
     /// Enable charge pump
     ChargePump(bool),
 }
@@ -65,20 +75,27 @@ impl Command {
             Command::ColumnAddressHigh(addr) => ([0x10 | (0xF & addr), 0, 0, 0, 0, 0, 0], 1),
             Command::PageAddress(page) => ([0xB0 | (page as u8), 0, 0, 0, 0, 0, 0], 1),
             Command::StartLine(line) => ([0x40 | (0x3F & line), 0, 0, 0, 0, 0, 0], 1),
+            Command::StartLineExt(line) => ([0xDC, 0x7F & line, 0, 0, 0, 0, 0], 2),
+            Command::VerticalAddressing(vert) => ([0x20 | (vert as u8), 0, 0, 0, 0, 0, 0], 1),
             Command::SegmentRemap(remap) => ([0xA0 | (remap as u8), 0, 0, 0, 0, 0, 0], 1),
             Command::Multiplex(ratio) => ([0xA8, ratio, 0, 0, 0, 0, 0], 2),
             Command::ReverseComDir(rev) => ([0xC0 | ((rev as u8) << 3), 0, 0, 0, 0, 0, 0], 1),
             Command::DisplayOffset(offset) => ([0xD3, offset, 0, 0, 0, 0, 0], 2),
-            Command::ComPinConfig(alt) => ([0xDA, 0x02 | ((alt as u8) << 4), 0, 0, 0, 0, 0], 2),
+            Command::ComPinConfig(alt, remap) => {
+                let config = 0x02 | ((alt as u8) << 4) | ((remap as u8) << 5);
+                ([0xDA, config, 0, 0, 0, 0, 0], 2)
+            }
             Command::DisplayClockDiv(fosc, div) => {
                 ([0xD5, ((0xF & fosc) << 4) | (0xF & div), 0, 0, 0, 0, 0], 2)
             }
             Command::PreChargePeriod(phase1, phase2) => (
-                [0x22, ((0xF & phase2) << 4) | (0xF & phase1), 0, 0, 0, 0, 0],
+                [0xD9, ((0xF & phase2) << 4) | (0xF & phase1), 0, 0, 0, 0, 0],
                 2,
             ),
-            Command::VcomhDeselect(level) => ([0x35, (level as u8) << 4, 0, 0, 0, 0, 0], 2),
+            Command::VcomhDeselect(level) => ([0xDB, level, 0, 0, 0, 0, 0], 2),
             Command::Noop => ([0xE3, 0, 0, 0, 0, 0, 0], 1),
+            Command::ReadModifyWrite => ([0xE0, 0, 0, 0, 0, 0, 0], 1),
+            Command::End => ([0xEE, 0, 0, 0, 0, 0, 0], 1),
             Command::ChargePump(en) => ([0xAD, 0x8A | (en as u8), 0, 0, 0, 0, 0], 2),
         };
 
@@ -106,6 +123,30 @@ pub enum Page {
     Page6 = 6,
     /// Page 7
     Page7 = 7,
+    /// Page 8
+    #[cfg(feature = "sh1107")]
+    Page8 = 8,
+    /// Page 9
+    #[cfg(feature = "sh1107")]
+    Page9 = 9,
+    /// Page 10
+    #[cfg(feature = "sh1107")]
+    Page10 = 10,
+    /// Page 11
+    #[cfg(feature = "sh1107")]
+    Page11 = 11,
+    /// Page 12
+    #[cfg(feature = "sh1107")]
+    Page12 = 12,
+    /// Page 13
+    #[cfg(feature = "sh1107")]
+    Page13 = 13,
+    /// Page 14
+    #[cfg(feature = "sh1107")]
+    Page14 = 14,
+    /// Page 15
+    #[cfg(feature = "sh1107")]
+    Page15 = 15,
 }
 
 impl From<u8> for Page {
@@ -119,6 +160,22 @@ impl From<u8> for Page {
             5 => Page::Page5,
             6 => Page::Page6,
             7 => Page::Page7,
+            #[cfg(feature = "sh1107")]
+            8 => Page::Page8,
+            #[cfg(feature = "sh1107")]
+            9 => Page::Page9,
+            #[cfg(feature = "sh1107")]
+            10 => Page::Page10,
+            #[cfg(feature = "sh1107")]
+            11 => Page::Page11,
+            #[cfg(feature = "sh1107")]
+            12 => Page::Page12,
+            #[cfg(feature = "sh1107")]
+            13 => Page::Page13,
+            #[cfg(feature = "sh1107")]
+            14 => Page::Page14,
+            #[cfg(feature = "sh1107")]
+            15 => Page::Page15,
             _ => panic!("Page too high"),
         }
     }
@@ -145,17 +202,3 @@ pub enum NFrames {
     /// 256 Frames
     F256 = 0b011,
 }
-
-/// Vcomh Deselect level
-#[derive(Debug, Clone, Copy)]
-#[allow(dead_code)]
-pub enum VcomhLevel {
-    /// 0.65 * Vcc
-    V065 = 0b001,
-    /// 0.77 * Vcc
-    V077 = 0b010,
-    /// 0.83 * Vcc
-    V083 = 0b011,
-    /// Auto
-    Auto = 0b100,
-}
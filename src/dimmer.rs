@@ -0,0 +1,92 @@
+//! Dim the display when idle
+//!
+//! An [`IdleDimmer`](struct.IdleDimmer.html) lowers the contrast once nothing has happened for a
+//! while and restores it on the next activity. It only needs a monotonic tick count from the
+//! application, in any unit, e.g. milliseconds from a system timer. Tick counts may wrap around.
+//!
+//! ```rust,ignore
+//! let mut dimmer = IdleDimmer::new(30_000, 0x08);
+//!
+//! loop {
+//!     let now = millis();
+//!
+//!     if button_pressed() {
+//!         dimmer.activity(&mut disp, now).unwrap();
+//!     }
+//!
+//!     dimmer.update(&mut disp, now).unwrap();
+//! }
+//! ```
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+/// Lowers the display contrast after a period without activity
+#[derive(Clone, Copy, Debug)]
+pub struct IdleDimmer {
+    timeout: u32,
+    dim_contrast: u8,
+    last_activity: u32,
+    // Contrast to go back to, only set while dimmed
+    saved_contrast: Option<u8>,
+}
+
+impl IdleDimmer {
+    /// Create a new dimmer that sets the contrast to `dim_contrast` once `timeout` ticks have
+    /// passed since the last activity. The idle time is counted from tick 0 until
+    /// [`activity`](#method.activity) is first called.
+    pub fn new(timeout: u32, dim_contrast: u8) -> Self {
+        Self {
+            timeout,
+            dim_contrast,
+            last_activity: 0,
+            saved_contrast: None,
+        }
+    }
+
+    /// Whether the display is currently dimmed
+    pub fn is_dimmed(&self) -> bool {
+        self.saved_contrast.is_some()
+    }
+
+    /// Record activity at tick `now`, restoring the contrast from before dimming if the display
+    /// was dimmed
+    pub fn activity<DI, BUF>(
+        &mut self,
+        display: &mut GraphicsMode<DI, BUF>,
+        now: u32,
+    ) -> Result<(), DI::Error>
+    where
+        DI: DisplayInterface,
+        BUF: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        self.last_activity = now;
+
+        match self.saved_contrast.take() {
+            Some(contrast) => display.set_contrast(contrast),
+            None => Ok(()),
+        }
+    }
+
+    /// Dim the display if it has been idle for the timeout at tick `now`. Call this regularly,
+    /// e.g. once per main loop iteration. Nothing is sent to the display unless it gets dimmed.
+    pub fn update<DI, BUF>(
+        &mut self,
+        display: &mut GraphicsMode<DI, BUF>,
+        now: u32,
+    ) -> Result<(), DI::Error>
+    where
+        DI: DisplayInterface,
+        BUF: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if self.is_dimmed() || now.wrapping_sub(self.last_activity) < self.timeout {
+            return Ok(());
+        }
+
+        let contrast = display.contrast();
+        display.set_contrast(self.dim_contrast)?;
+        self.saved_contrast = Some(contrast);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+//! Saved display settings
+
+use crate::displayrotation::DisplayRotation;
+use crate::displaysize::DisplaySize;
+use crate::mirror::Mirror;
+
+/// A snapshot of the user adjustable display settings, taken with
+/// [`GraphicsMode::config`](../mode/graphics/struct.GraphicsMode.html#method.config) and restored
+/// with [`GraphicsMode::apply_config`](../mode/graphics/struct.GraphicsMode.html#method.apply_config),
+/// e.g. to keep the user's preferences in flash across resets.
+///
+/// The display size can't change once the driver is built, so it isn't restored by
+/// `apply_config`; pass it to [`Builder::with_size`](../builder/struct.Builder.html#method.with_size)
+/// instead. The vertical and column offsets follow from the size.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DisplayConfig {
+    /// Display size
+    pub size: DisplaySize,
+    /// Display rotation
+    pub rotation: DisplayRotation,
+    /// Software mirroring of drawing coordinates
+    pub mirror: Mirror,
+    /// Display contrast
+    pub contrast: u8,
+    /// Whether the display colours are inverted
+    pub inverted: bool,
+}
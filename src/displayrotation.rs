@@ -1,9 +1,10 @@
 //! Display rotation
 
 /// Display rotation
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum DisplayRotation {
     /// No rotation, normal display
+    #[default]
     Rotate0,
     /// Rotate by 90 degress clockwise
     Rotate90,
@@ -1,7 +1,7 @@
 //! Display size
 
 /// Display size enumeration
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum DisplaySize {
     /// 128 by 64 pixels
     Display128x64,
@@ -9,15 +9,20 @@ pub enum DisplaySize {
     Display128x32,
     /// 132 by 64 pixels
     Display132x64,
+    /// 128 by 128 pixels, driven by the closely related SH1107 controller
+    #[cfg(feature = "sh1107")]
+    Display128x128,
 }
 
 impl DisplaySize {
     /// Get integral dimensions from DisplaySize
-    pub fn dimensions(&self) -> (u8, u8) {
+    pub const fn dimensions(&self) -> (u8, u8) {
         match *self {
             DisplaySize::Display128x64 => (128, 64),
             DisplaySize::Display128x32 => (128, 32),
             DisplaySize::Display132x64 => (132, 64),
+            #[cfg(feature = "sh1107")]
+            DisplaySize::Display128x128 => (128, 128),
         }
     }
 
@@ -27,6 +32,35 @@ impl DisplaySize {
             DisplaySize::Display128x64 => 2,
             DisplaySize::Display128x32 => 2,
             DisplaySize::Display132x64 => 0,
+            #[cfg(feature = "sh1107")]
+            DisplaySize::Display128x128 => 0,
+        }
+    }
+
+    /// Get the multiplex ratio the panel is initialised with, i.e. the number of COM lines
+    /// (rows) it drives minus one
+    ///
+    /// ```rust
+    /// # use sh1106::prelude::*;
+    /// assert_eq!(DisplaySize::Display128x64.multiplex_ratio(), 63);
+    /// assert_eq!(DisplaySize::Display128x32.multiplex_ratio(), 31);
+    /// ```
+    pub const fn multiplex_ratio(&self) -> u8 {
+        match *self {
+            DisplaySize::Display128x64 => 63,
+            DisplaySize::Display128x32 => 31,
+            DisplaySize::Display132x64 => 63,
+            #[cfg(feature = "sh1107")]
+            DisplaySize::Display128x128 => 127,
+        }
+    }
+
+    /// Whether this size is driven by an SH1107 rather than an SH1106 controller
+    pub fn is_sh1107(&self) -> bool {
+        match *self {
+            #[cfg(feature = "sh1107")]
+            DisplaySize::Display128x128 => true,
+            _ => false,
         }
     }
 }
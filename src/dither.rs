@@ -0,0 +1,53 @@
+//! Ordered dithering of grayscale images into the 1BPP display buffer
+//!
+//! Grayscale pixels are compared against a 4x4 Bayer matrix, tiled across the screen:
+//!
+//! ```text
+//!  0  8  2 10
+//! 12  4 14  6
+//!  3 11  1  9
+//! 15  7 13  5
+//! ```
+//!
+//! A pixel at screen position `(x, y)` with gray level `g` (`0` black, `255` white) is turned on
+//! if `g > BAYER[y % 4][x % 4] * 16 + 8`. The matrix is anchored to screen coordinates rather than
+//! image coordinates, so adjacent tiles drawn separately line up without visible seams.
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+/// 4x4 Bayer threshold matrix
+const BAYER: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Whether a pixel with gray level `gray` at screen position `x, y` should be lit
+fn dither(gray: u8, x: u32, y: u32) -> bool {
+    let threshold = BAYER[(y % 4) as usize][(x % 4) as usize] * 16 + 8;
+
+    gray > threshold
+}
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Draw an 8 bit grayscale image with its top left corner at `x, y`, dithering it to black and
+    /// white. `data` holds one byte per pixel, row by row, and `width` is the image width in
+    /// pixels. Any partial last row is ignored. Pixels are set or cleared, replacing what was in
+    /// the buffer.
+    pub fn draw_gray_dithered(&mut self, data: &[u8], width: u32, x: u32, y: u32) {
+        if width == 0 {
+            return;
+        }
+
+        for (row, line) in data.chunks_exact(width as usize).enumerate() {
+            let py = y.saturating_add(row as u32);
+
+            for (col, gray) in line.iter().enumerate() {
+                let px = x.saturating_add(col as u32);
+
+                self.set_pixel(px, py, dither(*gray, px, py) as u8);
+            }
+        }
+    }
+}
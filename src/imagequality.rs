@@ -0,0 +1,46 @@
+//! Panel tuning presets
+
+/// Preset combinations of contrast, precharge period and VCOM deselect level, applied with
+/// [`GraphicsMode::apply_preset`](../mode/graphics/struct.GraphicsMode.html#method.apply_preset).
+/// Each preset is a starting point; panels vary, so use the individual setters on
+/// [`DisplayProperties`](../properties/struct.DisplayProperties.html) to fine tune.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageQuality {
+    /// Maximum contrast and VCOM level with a short pre-charge and long discharge phase, for
+    /// crisp text in bright surroundings at the cost of current draw and panel wear
+    HighContrast,
+    /// Low contrast and VCOM level for battery powered devices and dark rooms
+    LowPower,
+    /// The settings `init` uses: mid contrast with the same precharge and VCOM level as
+    /// `HighContrast`
+    Balanced,
+}
+
+impl ImageQuality {
+    /// Get the contrast value sent to the display
+    pub const fn contrast(self) -> u8 {
+        match self {
+            ImageQuality::HighContrast => 0xFF,
+            ImageQuality::LowPower => 0x20,
+            ImageQuality::Balanced => 0x80,
+        }
+    }
+
+    /// Get the pre-charge and discharge periods sent to the display, in display clocks
+    pub const fn precharge_period(self) -> (u8, u8) {
+        match self {
+            ImageQuality::HighContrast => (0x1, 0xF),
+            ImageQuality::LowPower => (0x2, 0x2),
+            ImageQuality::Balanced => (0x1, 0xF),
+        }
+    }
+
+    /// Get the VCOM deselect level sent to the display
+    pub const fn vcom_deselect_level(self) -> u8 {
+        match self {
+            ImageQuality::HighContrast => 0x40,
+            ImageQuality::LowPower => 0x20,
+            ImageQuality::Balanced => 0x40,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Interface that is picked between I2C and SPI at runtime
+
+use hal::blocking::delay::DelayMs;
+
+use super::spi::NoResetPin;
+use super::{DisplayInterface, I2cInterface, SpiInterface};
+
+/// Either an I2C or an SPI display interface, for firmware that finds out how the display is
+/// wired at runtime.
+///
+/// Using this interface means only one copy of the driver is compiled instead of one per bus.
+/// The cost is a `match` on the interface variant for every command and data transfer, which is
+/// negligible next to the bus transfer itself.
+///
+/// An enum is used rather than a `dyn DisplayInterface` trait object because the error type is
+/// part of the trait, so interfaces with different errors can't share one trait object, and
+/// because it needs no allocator. The driver is blocking only; there is no async interface to
+/// erase, so async applications should flush from a task that may block for the length of a
+/// transfer.
+///
+/// ```rust,ignore
+/// let iface = if board_uses_spi {
+///     AnyInterface::from(SpiInterface::new(spi, dc, cs))
+/// } else {
+///     AnyInterface::from(I2cInterface::new(i2c, 0x3c))
+/// };
+///
+/// let mut disp: GraphicsMode<_> = Builder::new().connect(iface).into();
+/// ```
+pub enum AnyInterface<I2C, SPI, DC, CS, RST = NoResetPin> {
+    /// I2C interface
+    I2c(I2cInterface<I2C>),
+    /// SPI interface
+    Spi(SpiInterface<SPI, DC, CS, RST>),
+}
+
+/// Error returned by [`AnyInterface`](enum.AnyInterface.html), wrapping the error of the
+/// interface in use
+#[derive(Debug)]
+pub enum AnyError<I2cE, SpiE> {
+    /// Error from the I2C interface
+    I2c(I2cE),
+    /// Error from the SPI interface
+    Spi(SpiE),
+}
+
+impl<I2C, SPI, DC, CS, RST> From<I2cInterface<I2C>> for AnyInterface<I2C, SPI, DC, CS, RST> {
+    fn from(iface: I2cInterface<I2C>) -> Self {
+        AnyInterface::I2c(iface)
+    }
+}
+
+impl<I2C, SPI, DC, CS, RST> From<SpiInterface<SPI, DC, CS, RST>>
+    for AnyInterface<I2C, SPI, DC, CS, RST>
+{
+    fn from(iface: SpiInterface<SPI, DC, CS, RST>) -> Self {
+        AnyInterface::Spi(iface)
+    }
+}
+
+impl<I2C, SPI, DC, CS, RST> DisplayInterface for AnyInterface<I2C, SPI, DC, CS, RST>
+where
+    I2cInterface<I2C>: DisplayInterface,
+    SpiInterface<SPI, DC, CS, RST>: DisplayInterface,
+{
+    type Error = AnyError<
+        <I2cInterface<I2C> as DisplayInterface>::Error,
+        <SpiInterface<SPI, DC, CS, RST> as DisplayInterface>::Error,
+    >;
+
+    fn init(&mut self) -> Result<(), Self::Error> {
+        match self {
+            AnyInterface::I2c(iface) => iface.init().map_err(AnyError::I2c),
+            AnyInterface::Spi(iface) => iface.init().map_err(AnyError::Spi),
+        }
+    }
+
+    fn reset(&mut self, delay: &mut dyn DelayMs<u8>) -> Result<(), Self::Error> {
+        match self {
+            AnyInterface::I2c(iface) => iface.reset(delay).map_err(AnyError::I2c),
+            AnyInterface::Spi(iface) => iface.reset(delay).map_err(AnyError::Spi),
+        }
+    }
+
+    fn send_commands(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        match self {
+            AnyInterface::I2c(iface) => iface.send_commands(cmds).map_err(AnyError::I2c),
+            AnyInterface::Spi(iface) => iface.send_commands(cmds).map_err(AnyError::Spi),
+        }
+    }
+
+    fn send_data(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
+        match self {
+            AnyInterface::I2c(iface) => iface.send_data(buf).map_err(AnyError::I2c),
+            AnyInterface::Spi(iface) => iface.send_data(buf).map_err(AnyError::Spi),
+        }
+    }
+}
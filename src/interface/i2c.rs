@@ -2,14 +2,21 @@
 
 use hal;
 
-use super::DisplayInterface;
-use crate::command::Page;
+use super::{cycles_to_us, flush_transfers, DisplayInterface};
+use crate::displaysize::DisplaySize;
 use crate::Error;
 
+/// Largest number of data bytes sent in one I2C write
+const CHUNKLEN: usize = 128;
+
 /// SH1106 I2C communication interface
 pub struct I2cInterface<I2C> {
     i2c: I2C,
     addr: u8,
+    command_byte: u8,
+    data_byte: u8,
+    retries: u8,
+    max_chunk: usize,
 }
 
 impl<I2C> I2cInterface<I2C>
@@ -18,7 +25,61 @@ where
 {
     /// Create new sh1106 I2C interface
     pub fn new(i2c: I2C, addr: u8) -> Self {
-        Self { i2c, addr }
+        Self {
+            i2c,
+            addr,
+            command_byte: 0x00,
+            data_byte: 0x40,
+            retries: 0,
+            max_chunk: CHUNKLEN,
+        }
+    }
+
+    /// Override the control bytes sent before commands and data. The defaults are `0x00` for
+    /// commands and `0x40` for data as given in the datasheet, but some modules expect different
+    /// framing.
+    pub fn with_control_bytes(self, command_byte: u8, data_byte: u8) -> Self {
+        Self {
+            command_byte,
+            data_byte,
+            ..self
+        }
+    }
+
+    /// Retry each failed I2C write up to `retries` more times before returning the error. This
+    /// helps on noisy buses or long cables that occasionally NACK, but it can also hide genuine
+    /// wiring faults such as a wrong address or a missing pull-up. Defaults to no retries.
+    pub fn with_retries(self, retries: u8) -> Self {
+        Self { retries, ..self }
+    }
+
+    /// Limit the number of data bytes sent in a single I2C write to `max_chunk`, not counting the
+    /// control byte. Some HALs reject long writes, so lower this if flushing fails with an error
+    /// from the I2C peripheral. The default and maximum is 128 bytes, and values below 1 are
+    /// treated as 1.
+    pub fn with_max_chunk(self, max_chunk: usize) -> Self {
+        Self {
+            max_chunk: max_chunk.clamp(1, CHUNKLEN),
+            ..self
+        }
+    }
+}
+
+impl<I2C, CommE> I2cInterface<I2C>
+where
+    I2C: hal::blocking::i2c::Write<Error = CommE>,
+{
+    /// Write `bytes` to the display, retrying as configured
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error<CommE, ()>> {
+        let mut attempts_left = self.retries;
+
+        loop {
+            match self.i2c.write(self.addr, bytes) {
+                Ok(()) => return Ok(()),
+                Err(_) if attempts_left > 0 => attempts_left -= 1,
+                Err(e) => return Err(Error::Comm(e)),
+            }
+        }
     }
 }
 
@@ -35,52 +96,54 @@ where
     fn send_commands(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
         // Copy over given commands to new aray to prefix with command identifier
         let mut writebuf: [u8; 8] = [0; 8];
+        writebuf[0] = self.command_byte;
         writebuf[1..=cmds.len()].copy_from_slice(&cmds);
 
-        self.i2c
-            .write(self.addr, &writebuf[..=cmds.len()])
-            .map_err(Error::Comm)
+        self.write(&writebuf[..=cmds.len()])
     }
 
     fn send_data(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
-        // Display is always 128px wide
-        const CHUNKLEN: usize = 128;
-
         const BUFLEN: usize = CHUNKLEN + 1;
 
-        // Noop if the data buffer is empty
-        if buf.is_empty() {
-            return Ok(());
-        }
-
-        let mut page = Page::Page0 as u8;
-
-        // Display width plus 4 start bytes
+        // Display width plus the data control byte
         let mut writebuf: [u8; BUFLEN] = [0; BUFLEN];
 
-        writebuf[0] = 0x40; // Following bytes are data bytes
+        writebuf[0] = self.data_byte; // Following bytes are data bytes
 
-        for chunk in buf.chunks(CHUNKLEN) {
+        // The draw address is set by the caller, so data goes wherever the display currently
+        // points. Empty buffers send nothing.
+        for chunk in buf.chunks(self.max_chunk) {
             // Copy over all data from buffer, leaving the data command byte intact
-            writebuf[1..BUFLEN].copy_from_slice(&chunk);
-
-            self.i2c
-                .write(
-                    self.addr,
-                    &[
-                        0x00, // Command
-                        page, // Page address
-                        0x02, // Lower column address
-                        0x10, // Upper column address (always zero, base is 10h)
-                    ],
-                )
-                .map_err(Error::Comm)?;
-
-            self.i2c.write(self.addr, &writebuf).map_err(Error::Comm)?;
-
-            page += 1;
+            writebuf[1..=chunk.len()].copy_from_slice(chunk);
+
+            self.write(&writebuf[..=chunk.len()])?;
         }
 
         Ok(())
     }
 }
+
+/// Estimate how many microseconds a full frame flush of a `display_size` display takes over I2C
+/// clocked at `bus_hz`, e.g. `400_000` in fast mode, to help budget a frame loop or pick a bus
+/// speed. Every write costs a start and stop condition, the address byte and a control byte on
+/// top of its payload, and each byte takes 9 clock cycles including the acknowledge. Data is
+/// assumed to be sent in the default 128 byte chunks. Clock stretching and time spent in the HAL
+/// between writes aren't included, so real flushes take a bit longer.
+///
+/// ```rust
+/// # use sh1106::interface::i2c::estimated_flush_us;
+/// # use sh1106::prelude::*;
+/// assert_eq!(estimated_flush_us(DisplaySize::Display128x64, 400_000), 25_398);
+/// assert_eq!(estimated_flush_us(DisplaySize::Display128x64, 0), u32::MAX);
+/// ```
+pub fn estimated_flush_us(display_size: DisplaySize, bus_hz: u32) -> u32 {
+    let (commands, data_bytes) = flush_transfers(display_size);
+    let (width, height) = display_size.dimensions();
+    let data_writes = u32::from(height) / 8 * (width as usize).div_ceil(CHUNKLEN) as u32;
+
+    // Address and control byte, then the payload, 9 cycles a byte, plus start and stop
+    let write_cycles = |payload: u32| (2 + payload) * 9 + 2;
+    let cycles = commands * write_cycles(1) + data_writes * write_cycles(0) + data_bytes * 9;
+
+    cycles_to_us(cycles, bus_hz)
+}
@@ -47,9 +47,14 @@
 //! >;
 //! ```
 
+pub mod any;
 pub mod i2c;
 pub mod spi;
 
+use hal::blocking::delay::DelayMs;
+
+use crate::displaysize::DisplaySize;
+
 /// A method of communicating with sh1106
 pub trait DisplayInterface {
     /// Interface error type
@@ -57,11 +62,47 @@ pub trait DisplayInterface {
 
     /// Initialize device.
     fn init(&mut self) -> Result<(), Self::Error>;
+    /// Pulse the display reset pin, if the interface manages one. Does nothing by default.
+    fn reset(&mut self, _delay: &mut dyn DelayMs<u8>) -> Result<(), Self::Error> {
+        Ok(())
+    }
     /// Send a batch of up to 8 commands to display.
     fn send_commands(&mut self, cmd: &[u8]) -> Result<(), Self::Error>;
     /// Send data to display.
     fn send_data(&mut self, buf: &[u8]) -> Result<(), Self::Error>;
 }
 
+/// An interface that can also read display RAM back, such as the 8080 or 6800 parallel buses.
+/// The serial interfaces in this crate can't: reads aren't available in the SH1106's 4 wire SPI
+/// mode at all.
+pub trait ReadableInterface: DisplayInterface {
+    /// Read `buf.len()` bytes of display RAM at the current address
+    fn read_data(&mut self, buf: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// Count the single byte commands and the data bytes sent by a full frame flush: the draw
+/// pointer is reset before the frame and moved to the next page after every page
+pub(crate) fn flush_transfers(display_size: DisplaySize) -> (u32, u32) {
+    let (width, height) = display_size.dimensions();
+    let pages = u32::from(height) / 8;
+
+    // Page address plus the low and high column address nibbles
+    let commands = 3 * (pages + 1);
+
+    (commands, u32::from(width) * pages)
+}
+
+/// Turn a number of bus clock cycles at `bus_hz` into microseconds, rounding up
+pub(crate) fn cycles_to_us(cycles: u32, bus_hz: u32) -> u32 {
+    if bus_hz == 0 {
+        return u32::MAX;
+    }
+
+    let us = (u64::from(cycles) * 1_000_000).div_ceil(u64::from(bus_hz));
+
+    us.min(u64::from(u32::MAX)) as u32
+}
+
+pub use self::any::AnyInterface;
 pub use self::i2c::I2cInterface;
 pub use self::spi::SpiInterface;
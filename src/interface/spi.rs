@@ -1,18 +1,64 @@
 //! sh1106 SPI interface
 
 use hal;
+use hal::blocking::delay::DelayMs;
 use hal::digital::v2::OutputPin;
 
-use super::DisplayInterface;
+use super::{cycles_to_us, flush_transfers, DisplayInterface};
+use crate::displaysize::DisplaySize;
 use crate::Error;
 
+/// Pin that can reset the display, reporting errors as `PinE`, the error type of the other
+/// display pins. Implemented for any output pin with that error type, and for
+/// [`NoResetPin`](struct.NoResetPin.html) whatever the error type, so leaving the reset pin out
+/// works with any HAL.
+pub trait ResetPin<PinE> {
+    /// Drive the pin low
+    fn set_low(&mut self) -> Result<(), PinE>;
+
+    /// Drive the pin high
+    fn set_high(&mut self) -> Result<(), PinE>;
+}
+
+impl<P, PinE> ResetPin<PinE> for P
+where
+    P: OutputPin<Error = PinE>,
+{
+    fn set_low(&mut self) -> Result<(), PinE> {
+        OutputPin::set_low(self)
+    }
+
+    fn set_high(&mut self) -> Result<(), PinE> {
+        OutputPin::set_high(self)
+    }
+}
+
+/// Placeholder for an unconnected reset pin. Resetting the display through the interface does
+/// nothing.
+#[derive(Clone, Copy)]
+pub struct NoResetPin;
+
+impl<PinE> ResetPin<PinE> for NoResetPin {
+    fn set_low(&mut self) -> Result<(), PinE> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), PinE> {
+        Ok(())
+    }
+}
+
 /// SPI display interface.
 ///
-/// This combines the SPI peripheral and a data/command pin
-pub struct SpiInterface<SPI, DC, CS> {
+/// This combines the SPI peripheral and a data/command pin, with optional chip select and reset
+/// pins
+pub struct SpiInterface<SPI, DC, CS, RST = NoResetPin> {
     spi: SPI,
     dc: DC,
     cs: CS,
+    rst: RST,
+    // Drive DC low for data and high for commands instead of the other way around
+    invert_dc: bool,
 }
 
 impl<SPI, DC, CS, CommE, PinE> SpiInterface<SPI, DC, CS>
@@ -23,15 +69,56 @@ where
 {
     /// Create new SPI interface for communciation with sh1106
     pub fn new(spi: SPI, dc: DC, cs: CS) -> Self {
-        Self { spi, dc, cs }
+        Self {
+            spi,
+            dc,
+            cs,
+            rst: NoResetPin,
+            invert_dc: false,
+        }
     }
 }
 
-impl<SPI, DC, CS, CommE, PinE> DisplayInterface for SpiInterface<SPI, DC, CS>
+impl<SPI, DC, CS, RST> SpiInterface<SPI, DC, CS, RST> {
+    /// Use `rst` as the display reset pin. It is pulsed by
+    /// [`DisplayInterface::reset`](../trait.DisplayInterface.html#method.reset).
+    pub fn with_reset_pin<NEWRST>(self, rst: NEWRST) -> SpiInterface<SPI, DC, CS, NEWRST> {
+        SpiInterface {
+            spi: self.spi,
+            dc: self.dc,
+            cs: self.cs,
+            rst,
+            invert_dc: self.invert_dc,
+        }
+    }
+
+    /// Swap the data/command pin levels, driving DC low for display data and high for commands,
+    /// for boards that invert the DC line. Defaults to `false`: low for commands, high for data.
+    pub fn with_inverted_dc(self, invert_dc: bool) -> Self {
+        Self { invert_dc, ..self }
+    }
+}
+
+impl<SPI, DC, CS, RST, PinE> SpiInterface<SPI, DC, CS, RST>
+where
+    DC: OutputPin<Error = PinE>,
+{
+    /// Set the DC pin to select data (`true`) or commands (`false`)
+    fn set_dc(&mut self, data: bool) -> Result<(), PinE> {
+        if data != self.invert_dc {
+            self.dc.set_high()
+        } else {
+            self.dc.set_low()
+        }
+    }
+}
+
+impl<SPI, DC, CS, RST, CommE, PinE> DisplayInterface for SpiInterface<SPI, DC, CS, RST>
 where
     SPI: hal::blocking::spi::Write<u8, Error = CommE>,
     DC: OutputPin<Error = PinE>,
     CS: OutputPin<Error = PinE>,
+    RST: ResetPin<PinE>,
 {
     type Error = Error<CommE, PinE>;
 
@@ -39,24 +126,48 @@ where
         self.cs.set_high().map_err(Error::Pin)
     }
 
+    fn reset(&mut self, delay: &mut dyn DelayMs<u8>) -> Result<(), Self::Error> {
+        self.rst.set_high().map_err(Error::Pin)?;
+        delay.delay_ms(1);
+        self.rst.set_low().map_err(Error::Pin)?;
+        delay.delay_ms(10);
+        self.rst.set_high().map_err(Error::Pin)
+    }
+
     fn send_commands(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
         self.cs.set_low().map_err(Error::Pin)?;
-        self.dc.set_low().map_err(Error::Pin)?;
+        self.set_dc(false).map_err(Error::Pin)?;
 
         self.spi.write(&cmds).map_err(Error::Comm)?;
 
-        self.dc.set_high().map_err(Error::Pin)?;
+        self.set_dc(true).map_err(Error::Pin)?;
         self.cs.set_high().map_err(Error::Pin)
     }
 
     fn send_data(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
         self.cs.set_low().map_err(Error::Pin)?;
 
-        // 1 = data, 0 = command
-        self.dc.set_high().map_err(Error::Pin)?;
+        // 1 = data, 0 = command, unless the DC line is inverted
+        self.set_dc(true).map_err(Error::Pin)?;
 
         self.spi.write(&buf).map_err(Error::Comm)?;
 
         self.cs.set_high().map_err(Error::Pin)
     }
 }
+
+/// Estimate how many microseconds a full frame flush of a `display_size` display takes over SPI
+/// clocked at `bus_hz`, to help budget a frame loop or pick a bus speed. SPI has no per write
+/// overhead, so this counts 8 clock cycles for every command and data byte. Time spent toggling
+/// the DC and CS pins and in the HAL between writes isn't included.
+///
+/// ```rust
+/// # use sh1106::interface::spi::estimated_flush_us;
+/// # use sh1106::prelude::*;
+/// assert_eq!(estimated_flush_us(DisplaySize::Display128x64, 8_000_000), 1_051);
+/// ```
+pub fn estimated_flush_us(display_size: DisplaySize, bus_hz: u32) -> u32 {
+    let (commands, data_bytes) = flush_transfers(display_size);
+
+    cycles_to_us((commands + data_bytes) * 8, bus_hz)
+}
@@ -103,17 +103,52 @@ pub enum Error<CommE, PinE> {
     Comm(CommE),
     /// Pin setting error
     Pin(PinE),
+    /// Draw area is not page aligned or doesn't fit on the display
+    InvalidArea,
 }
 
 extern crate embedded_hal as hal;
 
+pub mod addressmode;
+#[cfg(feature = "animation")]
+pub mod animation;
+pub mod bitorder;
+pub mod blendop;
+pub mod blit;
 pub mod builder;
 mod command;
+#[cfg(feature = "dimmer")]
+pub mod dimmer;
+pub mod displayconfig;
 pub mod displayrotation;
 mod displaysize;
+#[cfg(feature = "dither")]
+pub mod dither;
+pub mod imagequality;
 pub mod interface;
+pub mod mirror;
+#[doc(hidden)]
+pub mod mock;
 pub mod mode;
+pub mod pixelformat;
 pub mod prelude;
+pub mod primitives;
 pub mod properties;
+pub mod region;
+#[cfg(feature = "critical-section")]
+pub mod shared;
+pub mod text;
+#[cfg(feature = "widgets")]
+pub mod widgets;
 
-pub use crate::builder::Builder;
+pub use crate::builder::{Builder, BuilderError};
+
+/// Version of this driver crate, e.g. for an "about" screen
+pub const DRIVER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Name of the display controller this driver is for. SH1107 displays are driven by the same
+/// code; use [`DisplaySize::is_sh1107`](prelude/enum.DisplaySize.html#method.is_sh1107) to tell
+/// them apart.
+pub const fn chip_id() -> &'static str {
+    "SH1106"
+}
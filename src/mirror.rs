@@ -0,0 +1,28 @@
+//! Display mirroring
+
+/// Mirror the image in software. Mirroring is applied to drawing coordinates after rotation, so
+/// `Horizontal` always flips left and right as seen on screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Mirror {
+    /// No mirroring
+    #[default]
+    None,
+    /// Flip left and right
+    Horizontal,
+    /// Flip top and bottom
+    Vertical,
+    /// Flip both axes
+    Both,
+}
+
+impl Mirror {
+    /// Whether left and right are flipped
+    pub fn is_horizontal(self) -> bool {
+        self == Mirror::Horizontal || self == Mirror::Both
+    }
+
+    /// Whether top and bottom are flipped
+    pub fn is_vertical(self) -> bool {
+        self == Mirror::Vertical || self == Mirror::Both
+    }
+}
@@ -0,0 +1,270 @@
+//! Stand-ins for the display bus, used by the examples in this documentation so they run without
+//! hardware. Not part of the public API.
+
+use core::convert::Infallible;
+
+use hal::blocking::delay::DelayMs;
+use hal::blocking::spi;
+use hal::digital::v2::OutputPin;
+
+use crate::displayrotation::DisplayRotation;
+use crate::displaysize::DisplaySize;
+use crate::interface::{DisplayInterface, ReadableInterface};
+use crate::mode::displaymode::DisplayModeTrait;
+use crate::mode::graphics::GraphicsMode;
+use crate::properties::DisplayProperties;
+use crate::Error;
+
+/// Number of command bytes a [`MockInterface`](struct.MockInterface.html) keeps
+const COMMAND_CAPACITY: usize = 512;
+
+/// Number of columns of controller RAM in a page
+const RAM_COLUMNS: usize = 132;
+
+/// Number of pages of controller RAM, enough for the SH1107
+const RAM_PAGES: usize = 16;
+
+/// Create display properties talking to a fresh [`MockInterface`](struct.MockInterface.html)
+pub fn properties(
+    size: DisplaySize,
+    rotation: DisplayRotation,
+) -> DisplayProperties<MockInterface> {
+    DisplayProperties::new(MockInterface::new(), size, rotation)
+}
+
+/// Create a graphics mode driver talking to a fresh [`MockInterface`](struct.MockInterface.html)
+pub fn graphics(size: DisplaySize, rotation: DisplayRotation) -> GraphicsMode<MockInterface> {
+    GraphicsMode::new(properties(size, rotation))
+}
+
+/// Display interface that goes nowhere. It keeps the command bytes sent to it, up to
+/// `COMMAND_CAPACITY`, and counts the data bytes, so examples can check what the driver sent.
+#[derive(Clone, Copy, Debug)]
+pub struct MockInterface {
+    commands: [u8; COMMAND_CAPACITY],
+    command_len: usize,
+    data_len: usize,
+}
+
+impl MockInterface {
+    /// Create an interface that hasn't been sent anything
+    pub const fn new() -> Self {
+        Self {
+            commands: [0; COMMAND_CAPACITY],
+            command_len: 0,
+            data_len: 0,
+        }
+    }
+
+    /// Get the command bytes sent so far, in the order they were sent
+    pub fn commands(&self) -> &[u8] {
+        &self.commands[..self.command_len]
+    }
+
+    /// Get the number of data bytes sent so far
+    pub fn data_len(&self) -> usize {
+        self.data_len
+    }
+}
+
+impl Default for MockInterface {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayInterface for MockInterface {
+    type Error = Error<(), ()>;
+
+    fn init(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn send_commands(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        for &cmd in cmds {
+            if let Some(slot) = self.commands.get_mut(self.command_len) {
+                *slot = cmd;
+                self.command_len += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn send_data(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
+        self.data_len += buf.len();
+
+        Ok(())
+    }
+}
+
+/// Display interface that keeps a model of the controller's display RAM, so examples can read it
+/// back. Commands are recorded like [`MockInterface`](struct.MockInterface.html) does, and the
+/// page and column address, read-modify-write mode and the dummy read after setting an address
+/// behave as on the controller. Data sent past the end of a page is dropped.
+#[derive(Clone, Copy, Debug)]
+pub struct MockRamInterface {
+    iface: MockInterface,
+    ram: [[u8; RAM_COLUMNS]; RAM_PAGES],
+    page: usize,
+    column: usize,
+    // Column to go back to when read-modify-write mode ends, if it's active
+    rmw_column: Option<usize>,
+    // Whether the next read returns the stale output latch rather than RAM
+    dummy_read: bool,
+}
+
+impl MockRamInterface {
+    /// Create an interface with all of display RAM cleared
+    pub const fn new() -> Self {
+        Self {
+            iface: MockInterface::new(),
+            ram: [[0; RAM_COLUMNS]; RAM_PAGES],
+            page: 0,
+            column: 0,
+            rmw_column: None,
+            dummy_read: true,
+        }
+    }
+
+    /// Get the command bytes sent so far, in the order they were sent
+    pub fn commands(&self) -> &[u8] {
+        self.iface.commands()
+    }
+
+    /// Get the RAM columns of `page`
+    pub fn page(&self, page: u8) -> &[u8; RAM_COLUMNS] {
+        &self.ram[usize::from(page)]
+    }
+
+    /// Get the RAM columns of `page` to change them
+    pub fn page_mut(&mut self, page: u8) -> &mut [u8; RAM_COLUMNS] {
+        &mut self.ram[usize::from(page)]
+    }
+}
+
+impl Default for MockRamInterface {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayInterface for MockRamInterface {
+    type Error = Error<(), ()>;
+
+    fn init(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn send_commands(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        // Every command is sent on its own, so only the opcode matters here
+        match cmds.first().copied() {
+            Some(low @ 0x00..=0x0F) => self.column = (self.column & 0xF0) | usize::from(low),
+            Some(high @ 0x10..=0x1F) => {
+                self.column = (self.column & 0x0F) | (usize::from(high & 0xF) << 4)
+            }
+            Some(page @ 0xB0..=0xBF) => self.page = usize::from(page & 0xF),
+            Some(0xE0) => self.rmw_column = Some(self.column),
+            Some(0xEE) => self.column = self.rmw_column.take().unwrap_or(self.column),
+            _ => (),
+        }
+
+        if let Some(0x00..=0x1F) | Some(0xB0..=0xBF) = cmds.first() {
+            self.dummy_read = true;
+        }
+
+        self.iface.send_commands(cmds)
+    }
+
+    fn send_data(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
+        for &byte in buf {
+            if let Some(cell) = self.ram[self.page].get_mut(self.column) {
+                *cell = byte;
+                self.column += 1;
+            }
+        }
+
+        self.iface.send_data(buf)
+    }
+}
+
+impl ReadableInterface for MockRamInterface {
+    fn read_data(&mut self, buf: &mut [u8]) -> Result<(), Self::Error> {
+        for byte in buf {
+            if self.dummy_read {
+                *byte = 0;
+                self.dummy_read = false;
+                continue;
+            }
+
+            *byte = self.ram[self.page].get(self.column).copied().unwrap_or(0);
+
+            // Reads only move the column on outside read-modify-write mode
+            if self.rmw_column.is_none() {
+                self.column += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// SPI bus that accepts every write
+#[derive(Clone, Copy, Debug)]
+pub struct MockSpi;
+
+impl spi::Write<u8> for MockSpi {
+    type Error = ();
+
+    fn write(&mut self, _words: &[u8]) -> Result<(), ()> {
+        Ok(())
+    }
+}
+
+impl spi::Transfer<u8> for MockSpi {
+    type Error = ();
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], ()> {
+        Ok(words)
+    }
+}
+
+/// Output pin that can't fail, like the pins of most HALs
+#[derive(Clone, Copy, Debug)]
+pub struct MockPin;
+
+impl OutputPin for MockPin {
+    type Error = Infallible;
+
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+/// Delay that returns straight away, adding up how long it was asked to wait
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MockDelay {
+    waited_ms: u32,
+}
+
+impl MockDelay {
+    /// Create a delay that hasn't waited yet
+    pub const fn new() -> Self {
+        Self { waited_ms: 0 }
+    }
+
+    /// Get the total number of milliseconds waited so far
+    pub fn waited_ms(&self) -> u32 {
+        self.waited_ms
+    }
+}
+
+impl DelayMs<u8> for MockDelay {
+    fn delay_ms(&mut self, ms: u8) {
+        self.waited_ms += u32::from(ms);
+    }
+}
@@ -7,7 +7,10 @@ use crate::properties::DisplayProperties;
 pub struct DisplayMode<MODE>(pub MODE);
 
 /// Trait with core functionality for display mode switching
-pub trait DisplayModeTrait<DI> {
+pub trait DisplayModeTrait<DI>
+where
+    DI: DisplayInterface,
+{
     /// Allocate all required data and initialise display for mode
     fn new(properties: DisplayProperties<DI>) -> Self;
 
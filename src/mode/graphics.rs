@@ -18,21 +18,67 @@
 use hal::blocking::delay::DelayMs;
 use hal::digital::v2::OutputPin;
 
+use crate::addressmode::AddressMode;
+use crate::bitorder::BitOrder;
+use crate::blendop::BlendOp;
+use crate::displayconfig::DisplayConfig;
 use crate::displayrotation::DisplayRotation;
+use crate::displaysize::DisplaySize;
+use crate::imagequality::ImageQuality;
 use crate::interface::DisplayInterface;
+use crate::mirror::Mirror;
 use crate::mode::displaymode::DisplayModeTrait;
-use crate::properties::DisplayProperties;
+use crate::pixelformat::{Mono, PixelFormat};
+use crate::properties::{DisplayProperties, InitReport, LuxMapping};
+use crate::region::Region;
 use crate::Error;
 
-const BUFFER_SIZE: usize = 132 * 64 / 8;
+/// Dirty page mask with every page set
+const ALL_PAGES: u16 = !0;
+
+#[cfg(not(feature = "sh1107"))]
+pub(crate) const BUFFER_SIZE: usize = buffer_size_for(DisplaySize::Display132x64);
+#[cfg(feature = "sh1107")]
+pub(crate) const BUFFER_SIZE: usize = buffer_size_for(DisplaySize::Display128x128);
+
+/// Get the number of buffer bytes needed to hold a full frame for a display of the given size.
+/// The rotation doesn't change the size. Use this to size the buffer passed to
+/// [`GraphicsMode::with_buffer`](struct.GraphicsMode.html#method.with_buffer).
+///
+/// ```rust
+/// # use sh1106::mode::graphics::buffer_size_for;
+/// # use sh1106::prelude::*;
+/// assert_eq!(buffer_size_for(DisplaySize::Display128x64), 1024);
+/// assert_eq!(buffer_size_for(DisplaySize::Display128x32), 512);
+///
+/// let buffer = [0u8; buffer_size_for(DisplaySize::Display128x64)];
+/// ```
+pub const fn buffer_size_for(size: DisplaySize) -> usize {
+    let (width, height) = size.dimensions();
+
+    (width as usize) * (height as usize) * (Mono::BITS_PER_PIXEL as usize) / 8
+}
 
 /// Graphics mode handler
-pub struct GraphicsMode<DI>
+///
+/// By default the display buffer is owned by this struct and is large enough for any supported
+/// display size. Use [`with_buffer`](#method.with_buffer) to draw into a buffer owned by the
+/// application instead.
+pub struct GraphicsMode<DI, BUF = [u8; BUFFER_SIZE]>
 where
     DI: DisplayInterface,
 {
     properties: DisplayProperties<DI>,
-    buffer: [u8; BUFFER_SIZE],
+    buffer: BUF,
+    // One bit per page that changed since the last flush
+    dirty: u16,
+    mirror: Mirror,
+    // Key passed to the last successful `render_if_changed`
+    render_key: Option<u32>,
+    // Frame skipping state for `flush_paced`
+    perceived_brightness: u8,
+    brightness_error: u16,
+    blanked: bool,
 }
 
 impl<DI> DisplayModeTrait<DI> for GraphicsMode<DI>
@@ -44,6 +90,12 @@ where
         GraphicsMode {
             properties,
             buffer: [0; BUFFER_SIZE],
+            dirty: ALL_PAGES,
+            mirror: Mirror::None,
+            render_key: None,
+            perceived_brightness: 255,
+            brightness_error: 0,
+            blanked: false,
         }
     }
 
@@ -53,13 +105,224 @@ where
     }
 }
 
-impl<DI> GraphicsMode<DI>
+impl<'a, DI> GraphicsMode<DI, &'a mut [u8]>
+where
+    DI: DisplayInterface,
+{
+    /// Create a new GraphicsMode instance that draws into `buffer` instead of allocating its own.
+    /// This avoids keeping a second copy of the framebuffer around in applications that already
+    /// have one. The buffer must be at least `width * height / 8` bytes long for the configured
+    /// display size, otherwise `properties` is given back as the error.
+    pub fn with_buffer(
+        properties: DisplayProperties<DI>,
+        buffer: &'a mut [u8],
+    ) -> Result<Self, DisplayProperties<DI>> {
+        if buffer.len() < buffer_size_for(properties.get_size()) {
+            return Err(properties);
+        }
+
+        Ok(GraphicsMode {
+            properties,
+            buffer,
+            dirty: ALL_PAGES,
+            mirror: Mirror::None,
+            render_key: None,
+            perceived_brightness: 255,
+            brightness_error: 0,
+            blanked: false,
+        })
+    }
+}
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
 where
     DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
 {
+    /// Release the display properties and the buffer
+    pub fn into_parts(self) -> (DisplayProperties<DI>, BUF) {
+        (self.properties, self.buffer)
+    }
+
     /// Clear the display buffer. You need to call `disp.flush()` for any effect on the screen
     pub fn clear(&mut self) {
-        self.buffer = [0; BUFFER_SIZE];
+        self.buffer.as_mut().iter_mut().for_each(|byte| *byte = 0);
+        self.dirty = ALL_PAGES;
+    }
+
+    /// Turn every pixel in the display buffer on (`true`) or off (`false`). This sets whole
+    /// buffer bytes at once, which is much faster than drawing every pixel, and the result
+    /// doesn't depend on the rotation. `embedded-graphics` has no clear operation in the version
+    /// used by this crate, so use this to clear to either color.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// for rotation in [DisplayRotation::Rotate0, DisplayRotation::Rotate90] {
+    ///     let mut disp = mock::graphics(DisplaySize::Display128x32, rotation);
+    ///
+    ///     disp.fill(true);
+    ///     assert!(disp.buffer().iter().all(|byte| *byte == 0xFF));
+    ///
+    ///     disp.fill(false);
+    ///     assert!(disp.buffer().iter().all(|byte| *byte == 0));
+    /// }
+    /// ```
+    pub fn fill(&mut self, on: bool) {
+        let length = self.buffer_len();
+        let value = if on { 0xFF } else { 0 };
+
+        self.buffer.as_mut()[..length]
+            .iter_mut()
+            .for_each(|byte| *byte = value);
+        self.dirty = ALL_PAGES;
+    }
+
+    /// Get the part of the display buffer that is sent to the display on flush. Bytes are laid
+    /// out page by page, each byte covering a column of 8 pixels within its page.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer.as_ref()[..self.buffer_len()]
+    }
+
+    /// Get the share of pixels that are on, from 0.0 to 1.0. OLED current draw grows with the
+    /// number of lit pixels, so this helps estimate power use or pick a brightness. It counts the
+    /// set bits of [`buffer`](#method.buffer), so it is independent of rotation and cheap enough
+    /// to call every frame.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x32, DisplayRotation::Rotate0);
+    /// assert_eq!(disp.lit_pixel_ratio(), 0.0);
+    ///
+    /// // The top half of the display
+    /// for y in 0..16 {
+    ///     disp.draw_pixel_run(0, y, 128, true);
+    /// }
+    /// assert_eq!(disp.lit_pixel_ratio(), 0.5);
+    /// ```
+    pub fn lit_pixel_ratio(&self) -> f32 {
+        let buffer = self.buffer();
+        let lit: u32 = buffer.iter().map(|byte| byte.count_ones()).sum();
+
+        lit as f32 / (buffer.len() * 8) as f32
+    }
+
+    /// Replace the display buffer contents with `data`, previously obtained from
+    /// [`buffer`](#method.buffer). Returns an error if the length of `data` doesn't match the
+    /// configured display size. The whole display is redrawn on the next flush.
+    #[allow(clippy::result_unit_err)]
+    pub fn load_buffer(&mut self, data: &[u8]) -> Result<(), ()> {
+        let length = self.buffer_len();
+
+        if data.len() != length {
+            return Err(());
+        }
+
+        self.buffer.as_mut()[..length].copy_from_slice(data);
+        self.dirty = ALL_PAGES;
+
+        Ok(())
+    }
+
+    /// Show a full screen 1 bit per pixel image, e.g. a boot logo. `data` is copied straight into
+    /// the buffer without any per pixel processing, so it must already be in the display's page
+    /// layout: one page of 8 pixel tall columns after another, the same layout as
+    /// [`buffer`](#method.buffer). `order` says which bit of each byte holds the top pixel of its
+    /// column; the display RAM is `LsbFirst`, which is what image2cpp's "Vertical - 1 bit per
+    /// pixel" output uses, while `MsbFirst` data has each byte's bits reversed as it is copied.
+    /// The image is drawn as is, ignoring the rotation and mirroring settings. Returns an error if
+    /// the length of `data` doesn't match the configured display size.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x32, DisplayRotation::Rotate0);
+    ///
+    /// // Vertical stripes, one pixel wide
+    /// let logo = [0xFF, 0x00].repeat(128 * 32 / 8 / 2);
+    /// assert_eq!(disp.draw_splash(&logo, BitOrder::LsbFirst), Ok(()));
+    /// assert!(disp.get_pixel(0, 31) && !disp.get_pixel(1, 31));
+    ///
+    /// // A line along the top of every page, stored with the top pixel in the MSB
+    /// let lines = [0x80; 128 * 32 / 8];
+    /// assert_eq!(disp.draw_splash(&lines, BitOrder::MsbFirst), Ok(()));
+    /// assert!(disp.get_pixel(5, 8) && !disp.get_pixel(5, 15));
+    ///
+    /// // A 128x64 image doesn't fit
+    /// assert_eq!(disp.draw_splash(&[0; 128 * 64 / 8], BitOrder::LsbFirst), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn draw_splash(&mut self, data: &[u8], order: BitOrder) -> Result<(), ()> {
+        self.load_buffer(data)?;
+
+        if order != BitOrder::LsbFirst {
+            for byte in self.buffer_mut() {
+                *byte = order.to_lsb_first(*byte);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Combine `overlay` into the display buffer using `op`. The overlay has the same layout as
+    /// [`buffer`](#method.buffer), e.g. a prerendered background or a copy of another
+    /// `GraphicsMode` buffer. Returns an error if the length of `overlay` doesn't match the
+    /// configured display size.
+    #[allow(clippy::result_unit_err)]
+    pub fn compose(&mut self, overlay: &[u8], op: BlendOp) -> Result<(), ()> {
+        let length = self.buffer_len();
+
+        if overlay.len() != length {
+            return Err(());
+        }
+
+        let pairs = self.buffer.as_mut()[..length].iter_mut().zip(overlay);
+
+        match op {
+            BlendOp::Or => pairs.for_each(|(byte, over)| *byte |= over),
+            BlendOp::And => pairs.for_each(|(byte, over)| *byte &= over),
+            BlendOp::Xor => pairs.for_each(|(byte, over)| *byte ^= over),
+            BlendOp::Replace => pairs.for_each(|(byte, over)| *byte = *over),
+        }
+
+        self.dirty = ALL_PAGES;
+
+        Ok(())
+    }
+
+    /// Display properties, for drawing routines implemented outside this module
+    pub(crate) fn properties(&self) -> &DisplayProperties<DI> {
+        &self.properties
+    }
+
+    /// Mutable access to the part of the buffer used by the configured display size for drawing
+    /// routines that write bytes directly. Marks the buffer as dirty.
+    pub(crate) fn buffer_mut(&mut self) -> &mut [u8] {
+        let length = self.buffer_len();
+        self.dirty = ALL_PAGES;
+
+        &mut self.buffer.as_mut()[..length]
+    }
+
+    /// Number of buffer bytes used by the configured display size
+    fn buffer_len(&self) -> usize {
+        let length = buffer_size_for(self.properties.get_size());
+
+        // The constructors make sure the buffer is big enough, so this only catches buffers
+        // that shrank afterwards, which `AsMut` implementations are free to do
+        debug_assert!(
+            self.buffer.as_ref().len() >= length,
+            "display buffer is {} bytes, but {:?} needs {}",
+            self.buffer.as_ref().len(),
+            self.properties.get_size(),
+            length
+        );
+
+        length
     }
 
     /// Reset display
@@ -79,78 +342,681 @@ where
         rst.set_high().map_err(Error::Pin)
     }
 
-    /// Write out data to display
+    /// Write out data to display. If sending fails part way through, the display is pointed back
+    /// at the start of the frame before the error is returned, so the next flush starts cleanly.
+    ///
+    /// Only the configured display size is sent, however big the buffer is. If the panel has
+    /// more rows than the configured size, e.g. a 128x64 panel set up as 128x32, the extra pages
+    /// are never written and keep whatever was in display RAM. Check the size passed to
+    /// [`Builder::with_size`](../../builder/struct.Builder.html#method.with_size) if part of the
+    /// screen shows noise or stale content.
     pub fn flush(&mut self) -> Result<(), DI::Error> {
-        let display_size = self.properties.get_size();
+        if let Err(e) = self.flush_frame() {
+            // Leave the display pointing at the origin so the next flush starts from a known
+            // state. The original error is more useful than any error from doing so.
+            let _ = self.properties.reset_pointer();
+
+            return Err(e);
+        }
+
+        self.dirty = 0;
+
+        Ok(())
+    }
+
+    /// Write out data to display using vertical addressing, which sets the draw address once per
+    /// frame instead of once per page. Only the SH1107 supports vertical addressing, so for other
+    /// displays this is the same as [`flush`](#method.flush).
+    ///
+    /// The saving is in commands, not data: a 128x128 frame needs one address setup instead of
+    /// one per page, which over I2C saves around 45 short command transfers per frame.
+    pub fn flush_vertical(&mut self) -> Result<(), DI::Error> {
+        if !self.properties.get_size().is_sh1107() {
+            return self.flush();
+        }
+
+        let length = self.buffer_len();
+
+        if let Err(e) = self
+            .properties
+            .draw_vertical(&self.buffer.as_ref()[..length])
+        {
+            let _ = self.properties.reset_pointer();
+
+            return Err(e);
+        }
+
+        self.dirty = 0;
+
+        Ok(())
+    }
+
+    /// Point the display RAM write pointer back at the top left of the screen, e.g. after using
+    /// [`goto`](#method.goto). `flush` does this by itself.
+    pub fn reset_pointer(&mut self) -> Result<(), DI::Error> {
+        self.properties.reset_pointer()
+    }
+
+    /// Write out data to display page by page, carrying on with the remaining pages if sending
+    /// one fails. Returns a bitmask of the pages that failed, bit `n` standing for page `n`, so
+    /// `0` means the whole frame was sent. This keeps as much of the display up to date as
+    /// possible on a flaky bus; the buffer stays dirty if any page failed.
+    pub fn flush_resilient(&mut self) -> u16 {
+        let mut failed = 0u16;
+
+        for page in 0..self.properties.page_count() {
+            if self.flush_page(page).is_err() {
+                failed |= 1 << page;
+            }
+        }
+
+        let _ = self.properties.reset_pointer();
 
-        // Ensure the display buffer is at the origin of the display before we send the full frame
-        // to prevent accidental offsets
-        let (display_width, display_height) = display_size.dimensions();
+        self.dirty = failed;
+
+        failed
+    }
+
+    fn flush_frame(&mut self) -> Result<(), DI::Error> {
+        match self.properties.get_address_mode() {
+            AddressMode::Column => {
+                // Ensure the display buffer is at the origin of the display before we send the
+                // full frame to prevent accidental offsets
+                self.properties.reset_pointer()?;
+
+                let length = self.buffer_len();
+
+                self.properties.draw(&self.buffer.as_ref()[..length])
+            }
+            AddressMode::Page => {
+                // The draw position won't advance to the next page by itself, so select each
+                // page explicitly
+                for page in 0..self.properties.page_count() {
+                    self.flush_page(page)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Send a single page of the buffer to the display
+    fn flush_page(&mut self, page: u8) -> Result<(), DI::Error> {
+        let display_size = self.properties.get_size();
+        let (display_width, _) = display_size.dimensions();
         let column_offset = display_size.column_offset();
+
+        let width = display_width as usize;
+        let start = page as usize * width;
+        let row = page * 8;
+
         self.properties.set_draw_area(
-            (column_offset, 0),
-            (display_width + column_offset, display_height),
+            (column_offset, row),
+            (display_width + column_offset, row + 8),
         )?;
+        self.properties
+            .draw(&self.buffer.as_ref()[start..start + width])
+    }
+
+    /// Clear the buffer, redraw it with `f` and flush it, but only if `key` differs from the key
+    /// of the last successful call. `key` should change whenever the screen contents would,
+    /// e.g. a hash of the state it shows, so expensive screens are only rendered when needed.
+    /// Returns `Ok(true)` if the screen was redrawn. If the flush fails, the next call redraws
+    /// the screen again even with the same key.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    ///
+    /// let mut renders = 0;
+    ///
+    /// for level in [3, 3, 5] {
+    ///     disp.render_if_changed(level, |disp| {
+    ///         renders += 1;
+    ///         disp.draw_pixel_run(0, 0, level, true);
+    ///     })
+    ///     .unwrap();
+    /// }
+    ///
+    /// assert_eq!(renders, 2);
+    /// assert!(disp.get_pixel(4, 0) && !disp.get_pixel(5, 0));
+    /// ```
+    pub fn render_if_changed<F>(&mut self, key: u32, f: F) -> Result<bool, DI::Error>
+    where
+        F: FnOnce(&mut Self),
+    {
+        if self.render_key == Some(key) {
+            return Ok(false);
+        }
+
+        self.render_key = None;
+        self.clear();
+        f(self);
+        self.flush()?;
+        self.render_key = Some(key);
+
+        Ok(true)
+    }
+
+    /// Write out data to display like [`flush`](#method.flush) and return how long it took.
+    /// `now` is called once before and once after the flush and should read a free running
+    /// timer, e.g. a cycle counter or a millisecond tick; the result is the difference between
+    /// the two readings in the same unit. Timer wraparound is handled.
+    pub fn flush_timed<F>(&mut self, mut now: F) -> Result<u32, DI::Error>
+    where
+        F: FnMut() -> u32,
+    {
+        let start = now();
+        self.flush()?;
+
+        Ok(now().wrapping_sub(start))
+    }
+
+    /// Write out data to display only if the buffer has changed since the last flush. Only the
+    /// pages that changed are sent, so updating a single line of text is much cheaper than a full
+    /// flush. Returns `Ok(true)` if anything was sent and `Ok(false)` if there was nothing to send.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    /// disp.flush().unwrap();
+    /// assert!(!disp.flush_if_dirty().unwrap());
+    ///
+    /// disp.set_pixel(10, 20, 1);
+    /// assert!(disp.flush_if_dirty().unwrap());
+    /// assert!(!disp.is_dirty());
+    ///
+    /// // One full frame, then just the page holding the pixel
+    /// assert_eq!(disp.release_interface().data_len(), 128 * 8 + 128);
+    /// ```
+    pub fn flush_if_dirty(&mut self) -> Result<bool, DI::Error> {
+        let all_pages = self.page_mask();
+
+        if self.dirty & all_pages == 0 {
+            return Ok(false);
+        }
+
+        if self.dirty & all_pages == all_pages {
+            self.flush()?;
+
+            return Ok(true);
+        }
+
+        for page in 0..self.properties.page_count() {
+            if let Err(e) = self.flush_dirty_page(page) {
+                let _ = self.properties.reset_pointer();
+
+                return Err(e);
+            }
+        }
+
+        self.properties.reset_pointer()?;
+
+        Ok(true)
+    }
+
+    /// Set the brightness [`flush_paced`](#method.flush_paced) simulates by blanking a share of
+    /// frames, from `0` (always blank) to `255` (never blank, the default). This is a fallback
+    /// for panels whose contrast setting barely changes their brightness; prefer
+    /// [`set_contrast`](#method.set_contrast) where it works.
+    pub fn set_perceived_brightness(&mut self, level: u8) {
+        self.perceived_brightness = level;
+    }
+
+    /// Call at a steady rate, once per frame, to show the buffer at the level set with
+    /// [`set_perceived_brightness`](#method.set_perceived_brightness). On each call the display
+    /// is either shown, flushing any changed pages, or blanked by turning it off, with the shown
+    /// calls spread as evenly as possible. Only changes between shown and blank are sent, so a
+    /// frame costs a few command bytes at most on top of the changed pages.
+    ///
+    /// This trades brightness for flicker: the lower the level and the slower the call rate, the
+    /// more visible the flicker. Call it at 200 Hz or more, e.g. from a timer interrupt, and
+    /// avoid very low levels; below around a quarter of full brightness the flicker is usually
+    /// noticeable.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    /// disp.set_perceived_brightness(128);
+    ///
+    /// for _ in 0..4 {
+    ///     disp.flush_paced().unwrap();
+    /// }
+    ///
+    /// // Every other frame is blanked: display off, on, off, on
+    /// let iface = disp.release_interface();
+    /// let switches: Vec<u8> =
+    ///     iface.commands().iter().copied().filter(|cmd| *cmd == 0xAE || *cmd == 0xAF).collect();
+    /// assert_eq!(switches, [0xAE, 0xAF, 0xAE, 0xAF]);
+    /// ```
+    pub fn flush_paced(&mut self) -> Result<(), DI::Error> {
+        self.brightness_error += u16::from(self.perceived_brightness);
+
+        let show = self.brightness_error >= 255;
+
+        if show {
+            self.brightness_error -= 255;
+            self.flush_if_dirty()?;
+        }
+
+        if show == self.blanked {
+            self.properties.display_on(show)?;
+            self.blanked = !show;
+        }
+
+        Ok(())
+    }
 
-        let length = (display_width as usize) * (display_height as usize) / 8;
+    /// Whether the buffer has been modified since the last flush
+    pub fn is_dirty(&self) -> bool {
+        self.dirty & self.page_mask() != 0
+    }
+
+    /// Mark every page as changed so the next [`flush_if_dirty`](#method.flush_if_dirty) resends
+    /// the whole frame, e.g. after the display lost its contents to a reset or brownout
+    pub fn force_redraw(&mut self) {
+        self.dirty = ALL_PAGES;
+    }
+
+    /// Send `page` to the display if it changed since the last flush. Returns whether the page
+    /// was sent; pages past the bottom of the display are never dirty.
+    pub(crate) fn flush_dirty_page(&mut self, page: u8) -> Result<bool, DI::Error> {
+        if page >= self.properties.page_count() || self.dirty & (1 << page) == 0 {
+            return Ok(false);
+        }
+
+        self.flush_page(page)?;
+        self.dirty &= !(1 << page);
 
-        self.properties.draw(&self.buffer[..length])
+        Ok(true)
+    }
+
+    /// Dirty page mask covering every page of the configured display size
+    fn page_mask(&self) -> u16 {
+        ALL_PAGES >> (16 - self.properties.page_count())
+    }
+
+    /// Mark the page holding buffer byte `idx` as changed
+    fn mark_byte_dirty(&mut self, idx: usize) {
+        let (display_width, _) = self.properties.get_size().dimensions();
+
+        self.dirty |= 1 << (idx / display_width as usize);
     }
 
     /// Turn a pixel on or off. A non-zero `value` is treated as on, `0` as off. If the X and Y
     /// coordinates are out of the bounds of the display, this method call is a noop.
     pub fn set_pixel(&mut self, x: u32, y: u32, value: u8) {
+        let (idx, bit) = match self.pixel_location(x, y) {
+            Some(location) => location,
+            None => return,
+        };
+
+        let byte = &mut self.buffer.as_mut()[idx];
+        *byte = Mono::pack(*byte, bit, value);
+
+        self.mark_byte_dirty(idx);
+    }
+
+    /// Flip the pixel at `x, y` from on to off or from off to on, taking into account the
+    /// rotation like `set_pixel`. Pixels outside the display are ignored.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate270);
+    /// disp.set_pixel(5, 5, 1);
+    ///
+    /// disp.toggle_pixel(5, 5);
+    /// disp.toggle_pixel(6, 5);
+    /// assert!(!disp.get_pixel(5, 5));
+    /// assert!(disp.get_pixel(6, 5));
+    ///
+    /// disp.toggle_pixel(5, 5);
+    /// disp.toggle_pixel(6, 5);
+    /// assert!(disp.get_pixel(5, 5));
+    /// assert!(!disp.get_pixel(6, 5));
+    /// ```
+    pub fn toggle_pixel(&mut self, x: u32, y: u32) {
+        if let Some((idx, bit)) = self.pixel_location(x, y) {
+            self.buffer.as_mut()[idx] ^= bit;
+            self.mark_byte_dirty(idx);
+        }
+    }
+
+    /// Get whether the pixel at `x, y` is on in the buffer, using the same coordinates as
+    /// `set_pixel`. Pixels outside the display are always off.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// for rotation in [
+    ///     DisplayRotation::Rotate0,
+    ///     DisplayRotation::Rotate90,
+    ///     DisplayRotation::Rotate180,
+    ///     DisplayRotation::Rotate270,
+    /// ] {
+    ///     let mut disp = mock::graphics(DisplaySize::Display128x64, rotation);
+    ///     let (width, height) = disp.get_dimensions();
+    ///     let (width, height) = (u32::from(width), u32::from(height));
+    ///
+    ///     for (x, y) in (0..width + 4).flat_map(|x| (0..height + 4).map(move |y| (x, y))) {
+    ///         disp.set_pixel(x, y, 1);
+    ///         assert_eq!(disp.get_pixel(x, y), x < width && y < height);
+    ///
+    ///         if x < width && y < height {
+    ///             // The controller flips the panel for 180 and 270 degrees, so their buffers are
+    ///             // laid out like 0 and 90 degrees
+    ///             let (column, row) = match rotation {
+    ///                 DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (x, y),
+    ///                 DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (y, x),
+    ///             };
+    ///             let expected = ((row / 8 * 128 + column) as usize, 1 << (row % 8));
+    ///             let mut lit = disp.buffer().iter().enumerate().filter(|(_, byte)| **byte != 0);
+    ///
+    ///             assert_eq!(lit.next().map(|(idx, byte)| (idx, *byte)), Some(expected));
+    ///             assert_eq!(lit.next(), None);
+    ///         }
+    ///
+    ///         disp.set_pixel(x, y, 0);
+    ///         assert!(!disp.get_pixel(x, y));
+    ///     }
+    ///
+    ///     // Nothing drawn off screen may end up in the buffer
+    ///     disp.set_pixel(width, 0, 1);
+    ///     disp.set_pixel(0, height, 1);
+    ///     assert!(disp.buffer().iter().all(|byte| *byte == 0));
+    /// }
+    /// ```
+    pub fn get_pixel(&self, x: u32, y: u32) -> bool {
+        match self.pixel_location(x, y) {
+            Some((idx, bit)) => Mono::unpack(self.buffer.as_ref()[idx], bit) != 0,
+            None => false,
+        }
+    }
+
+    /// Iterate over every pixel of the display as `(x, y, on)`, row by row from the top left, in
+    /// the same coordinates as `get_pixel`. Handy for checking rendered content in host tests
+    /// without decoding buffer bytes by hand.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x32, DisplayRotation::Rotate90);
+    /// disp.set_pixel(3, 100, 1);
+    ///
+    /// assert_eq!(disp.pixels().count(), 128 * 32);
+    /// assert!(disp.pixels().filter(|(_, _, on)| *on).eq([(3, 100, true)].iter().copied()));
+    /// ```
+    pub fn pixels(&self) -> impl Iterator<Item = (u8, u8, bool)> + '_ {
+        let (width, height) = self.get_dimensions();
+
+        (0..height).flat_map(move |y| {
+            (0..width).map(move |x| (x, y, self.get_pixel(u32::from(x), u32::from(y))))
+        })
+    }
+
+    /// Map drawing coordinates `x, y`, as taken by `set_pixel`, to the `(column, row)` of display
+    /// RAM they end up in, taking into account the rotation and mirroring. Returns `None` for
+    /// points off the display. This lets renderers outside this crate write to
+    /// [`buffer`](#method.buffer) directly while staying correct in every rotation. Rotating by
+    /// 180 degrees is done by the display itself, so it maps the same as no rotation.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// for (rotation, expected) in [
+    ///     (DisplayRotation::Rotate0, Some((10, 20))),
+    ///     (DisplayRotation::Rotate90, Some((20, 10))),
+    ///     (DisplayRotation::Rotate180, Some((10, 20))),
+    ///     (DisplayRotation::Rotate270, Some((20, 10))),
+    /// ] {
+    ///     let mut disp = mock::graphics(DisplaySize::Display128x64, rotation);
+    ///     assert_eq!(disp.map_coord(10, 20), expected);
+    ///
+    ///     // The mapped point is where set_pixel puts the pixel
+    ///     let (column, row) = disp.map_coord(10, 20).unwrap();
+    ///     disp.set_pixel(10, 20, 1);
+    ///     assert_eq!(disp.buffer()[row as usize / 8 * 128 + column as usize], 1 << (row % 8));
+    ///
+    ///     let (width, height) = disp.get_dimensions();
+    ///     assert_eq!(disp.map_coord(u32::from(width), 0), None);
+    ///     assert_eq!(disp.map_coord(0, u32::from(height)), None);
+    ///
+    ///     // Mirroring both axes moves the bottom right corner to the start of display RAM
+    ///     disp.set_mirror(Mirror::Both);
+    ///     let (width, height) = (u32::from(width), u32::from(height));
+    ///     assert_eq!(disp.map_coord(width - 1, height - 1), Some((0, 0)));
+    /// }
+    /// ```
+    pub fn map_coord(&self, x: u32, y: u32) -> Option<(u32, u32)> {
+        let (x, y) = self.apply_mirror(x, y)?;
+        let (display_width, display_height) = self.properties.get_size().dimensions();
+
+        // Rotating by 90 or 270 degrees swaps buffer columns and rows
+        let (column, row) = match self.properties.get_rotation() {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (x, y),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (y, x),
+        };
+
+        if column >= u32::from(display_width) || row >= u32::from(display_height) {
+            return None;
+        }
+
+        Some((column, row))
+    }
+
+    /// Find the buffer byte and bit mask for the pixel at `x, y`, taking into account the
+    /// rotation and mirroring, or `None` if the pixel is off the display
+    fn pixel_location(&self, x: u32, y: u32) -> Option<(usize, u8)> {
+        let (column, row) = self.map_coord(x, y)?;
+        let (display_width, _) = self.properties.get_size().dimensions();
+
+        Some(Mono::locate(
+            column as usize,
+            row as usize,
+            display_width as usize,
+        ))
+    }
+
+    /// Turn a horizontal run of `len` pixels starting at `x, y` on or off. This is cheaper than
+    /// calling `set_pixel` for every pixel and is intended for scanline based rendering. Pixels
+    /// that fall outside the display are ignored.
+    pub fn draw_pixel_run(&mut self, x: u32, y: u32, len: u32, on: bool) {
         let (display_width, _) = self.properties.get_size().dimensions();
-        let display_rotation = self.properties.get_rotation();
+        let (width, height) = self.properties.get_dimensions();
+        let display_width = display_width as usize;
 
-        let idx = match display_rotation {
+        if x >= width as u32 || y >= height as u32 || len == 0 {
+            return;
+        }
+
+        let end = x.saturating_add(len).min(width as u32);
+
+        let (x, end) = if self.mirror.is_horizontal() {
+            (width as u32 - end, width as u32 - x)
+        } else {
+            (x, end)
+        };
+        let y = if self.mirror.is_vertical() {
+            height as u32 - 1 - y
+        } else {
+            y
+        };
+
+        match self.properties.get_rotation() {
             DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                if x >= display_width as u32 {
-                    return;
+                // The whole run lives in a single page, so every byte shares the same bit
+                let start = (y as usize) / 8 * display_width;
+                let bit = 1 << (y % 8);
+                let bytes = &mut self.buffer.as_mut()[start + x as usize..start + end as usize];
+
+                if on {
+                    bytes.iter_mut().for_each(|byte| *byte |= bit);
+                } else {
+                    bytes.iter_mut().for_each(|byte| *byte &= !bit);
                 }
-                ((y as usize) / 8 * display_width as usize) + (x as usize)
-            }
 
+                self.dirty |= 1 << (y / 8);
+            }
             DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                if y >= display_width as u32 {
-                    return;
+                // The run walks down a single buffer column, crossing page boundaries
+                for x in x..end {
+                    let byte =
+                        &mut self.buffer.as_mut()[(x as usize) / 8 * display_width + (y as usize)];
+                    let bit = 1 << (x % 8);
+
+                    if on {
+                        *byte |= bit;
+                    } else {
+                        *byte &= !bit;
+                    }
+                }
+
+                for page in x / 8..=(end - 1) / 8 {
+                    self.dirty |= 1 << page;
                 }
-                ((x as usize) / 8 * display_width as usize) + (y as usize)
             }
-        };
+        }
+    }
+
+    /// Set or clear a `width` by `height` rectangle with its top left corner at `x, y`, one
+    /// pixel run per row. Rows below the display are skipped.
+    pub(crate) fn fill_area(&mut self, x: u32, y: u32, width: u32, height: u32, on: bool) {
+        let (_, display_height) = self.get_dimensions();
+        let bottom = y.saturating_add(height).min(u32::from(display_height));
+
+        for row in y..bottom {
+            self.draw_pixel_run(x, row, width, on);
+        }
+    }
+
+    /// Scroll the buffer contents vertically by `dy` pixels, taking into account the current
+    /// rotation of the display. Positive values move the contents down, negative values move them
+    /// up. Rows scrolled in are cleared. Scrolling by the display height or more clears the whole
+    /// buffer.
+    ///
+    /// Unlike the SSD1306, the SH1106 has no hardware scrolling commands, so all scrolling is done
+    /// in the buffer and shows up on the next flush. To pause a scrolling animation, stop calling
+    /// this method; there is no scroll state on the display to preserve, and display RAM can be
+    /// written at any time.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    ///
+    /// disp.set_pixel(3, 5, 1);
+    /// disp.scroll_buffer(2);
+    /// assert!(disp.get_pixel(3, 7) && !disp.get_pixel(3, 5));
+    ///
+    /// // Shifts of the display height or more clear everything, however large
+    /// disp.fill(true);
+    /// disp.scroll_buffer(i32::MIN);
+    /// assert_eq!(disp.lit_pixel_ratio(), 0.0);
+    /// ```
+    pub fn scroll_buffer(&mut self, dy: i32) {
+        let (display_width, display_height) = self.properties.get_size().dimensions();
+        let (_, height) = self.properties.get_dimensions();
+
+        if dy == 0 {
+            return;
+        }
 
-        if idx >= self.buffer.len() {
+        if dy.unsigned_abs() >= u32::from(height) {
+            self.clear();
             return;
         }
 
-        let (byte, bit) = match display_rotation {
+        // Content moving down on screen moves up in a vertically mirrored buffer
+        let dy = if self.mirror.is_vertical() { -dy } else { dy };
+
+        let width = display_width as usize;
+        let pages = display_height as usize / 8;
+        let length = self.buffer_len();
+
+        match self.properties.get_rotation() {
             DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                let byte =
-                    &mut self.buffer[((y as usize) / 8 * display_width as usize) + (x as usize)];
-                let bit = 1 << (y % 8);
+                // Each buffer column holds a vertical strip of pixels spread over all pages
+                let mask = if display_height >= 128 {
+                    !0u128
+                } else {
+                    (1u128 << display_height) - 1
+                };
+
+                for column in 0..width {
+                    let mut strip = 0u128;
+
+                    for page in 0..pages {
+                        strip |=
+                            u128::from(self.buffer.as_ref()[page * width + column]) << (page * 8);
+                    }
 
-                (byte, bit)
+                    strip = if dy > 0 { strip << dy } else { strip >> -dy } & mask;
+
+                    for page in 0..pages {
+                        self.buffer.as_mut()[page * width + column] = (strip >> (page * 8)) as u8;
+                    }
+                }
             }
             DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                let byte =
-                    &mut self.buffer[((x as usize) / 8 * display_width as usize) + (y as usize)];
-                let bit = 1 << (x % 8);
+                // Rows on screen are columns in the buffer, so move whole bytes within each page
+                let shift = dy.unsigned_abs() as usize;
 
-                (byte, bit)
+                for page in self.buffer.as_mut()[..length].chunks_mut(width) {
+                    if dy > 0 {
+                        page.copy_within(..width - shift, shift);
+                        page[..shift].iter_mut().for_each(|byte| *byte = 0);
+                    } else {
+                        page.copy_within(shift.., 0);
+                        page[width - shift..].iter_mut().for_each(|byte| *byte = 0);
+                    }
+                }
             }
-        };
-
-        if value == 0 {
-            *byte &= !bit;
-        } else {
-            *byte |= bit;
         }
+
+        self.dirty = ALL_PAGES;
     }
 
-    /// Display is set up in column mode, i.e. a byte walks down a column of 8 pixels from
-    /// column 0 on the left, to column _n_ on the right
+    /// Initialise the display using the addressing mode set in the builder. In both modes a byte
+    /// walks down a column of 8 pixels from column 0 on the left, to column _n_ on the right.
     pub fn init(&mut self) -> Result<(), DI::Error> {
-        self.properties.init_column_mode()
+        self.properties.init()
+    }
+
+    /// Pulse the reset pin passed to the builder, then initialise the display. Use
+    /// [`reset`](#method.reset) instead if the reset pin isn't managed by the interface.
+    pub fn init_with_reset<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), DI::Error>
+    where
+        DELAY: DelayMs<u8>,
+    {
+        self.properties.reset(delay)?;
+        self.properties.init_with_delay(delay)
+    }
+
+    /// Initialise the display, waiting after enabling the charge pump for the delay set with
+    /// [`Builder::with_init_delay_ms`](../../builder/struct.Builder.html#method.with_init_delay_ms)
+    pub fn init_with_delay<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), DI::Error>
+    where
+        DELAY: DelayMs<u8>,
+    {
+        self.properties.init_with_delay(delay)
+    }
+
+    /// Initialise the display like [`init`](#method.init) and report the configuration that was
+    /// sent to it
+    pub fn init_verbose(&mut self) -> Result<InitReport, DI::Error> {
+        self.properties.init_verbose()
     }
 
     /// Get display dimensions, taking into account the current rotation of the display
@@ -158,10 +1024,290 @@ where
         self.properties.get_dimensions()
     }
 
+    /// Get the current display rotation, as set by the builder or
+    /// [`set_rotation`](#method.set_rotation)
+    pub fn rotation(&self) -> DisplayRotation {
+        self.properties.get_rotation()
+    }
+
+    /// Get the number of 8 pixel tall pages on the configured display, e.g. 8 for a 128x64
+    /// display or 4 for a 128x32 display. Rotation is not taken into account.
+    pub fn page_count(&self) -> u8 {
+        self.properties.page_count()
+    }
+
+    /// Get the area that can be drawn to as `(x, y, width, height)` in the coordinates accepted by
+    /// `set_pixel` and the other drawing methods, taking into account the current rotation. The
+    /// panel's column offset is applied when the buffer is flushed, so the area always starts at
+    /// `(0, 0)`; don't add the offset to drawing coordinates.
+    pub fn drawable_area(&self) -> (u8, u8, u8, u8) {
+        let (width, height) = self.properties.get_dimensions();
+
+        (0, 0, width, height)
+    }
+
     /// Set the display rotation
     pub fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DI::Error> {
         self.properties.set_rotation(rot)
     }
+
+    /// Set the display rotation used to map drawing coordinates to the buffer, without sending
+    /// the remap commands to the display. Only use this if the panel orientation is configured
+    /// some other way; `set_rotation` is right for most applications.
+    pub fn set_rotation_software_only(&mut self, rot: DisplayRotation) {
+        self.properties.set_rotation_software_only(rot)
+    }
+
+    /// Set the software mirroring applied to drawing coordinates. Mirroring is applied on top of
+    /// the current rotation and only affects pixels drawn afterwards.
+    pub fn set_mirror(&mut self, mirror: Mirror) {
+        self.mirror = mirror;
+    }
+
+    /// Get the software mirroring applied to drawing coordinates
+    pub fn get_mirror(&self) -> Mirror {
+        self.mirror
+    }
+
+    /// Invert the display, showing pixels that are on as dark. This changes how the whole panel
+    /// is shown, not the buffer.
+    pub fn set_invert(&mut self, inverted: bool) -> Result<(), DI::Error> {
+        self.properties.set_invert(inverted)
+    }
+
+    /// Get the current display settings, e.g. to save them and restore them later with
+    /// [`apply_config`](#method.apply_config)
+    pub fn config(&self) -> DisplayConfig {
+        DisplayConfig {
+            size: self.properties.get_size(),
+            rotation: self.properties.get_rotation(),
+            mirror: self.mirror,
+            contrast: self.properties.contrast(),
+            inverted: self.properties.is_inverted(),
+        }
+    }
+
+    /// Restore display settings saved with [`config`](#method.config). The rotation, mirroring,
+    /// contrast and inversion are applied straight away; the size is fixed when the driver is
+    /// built, so `config.size` is ignored. Redraw and flush the buffer afterwards if the rotation
+    /// or mirroring changed.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// # let new_display = || mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    /// let mut disp = new_display();
+    /// disp.set_rotation(DisplayRotation::Rotate180).unwrap();
+    /// disp.set_mirror(Mirror::Horizontal);
+    /// disp.set_contrast(0x20).unwrap();
+    /// disp.set_invert(true).unwrap();
+    /// let saved = disp.config();
+    ///
+    /// let mut restored = new_display();
+    /// restored.apply_config(saved).unwrap();
+    /// assert_eq!(restored.config(), saved);
+    /// ```
+    pub fn apply_config(&mut self, config: DisplayConfig) -> Result<(), DI::Error> {
+        self.properties.set_rotation(config.rotation)?;
+        self.properties.set_contrast(config.contrast)?;
+        self.properties.set_invert(config.inverted)?;
+        self.mirror = config.mirror;
+
+        Ok(())
+    }
+
+    /// Map drawing coordinates through the mirror setting, or `None` if they are off the display
+    fn apply_mirror(&self, x: u32, y: u32) -> Option<(u32, u32)> {
+        if self.mirror == Mirror::None {
+            return Some((x, y));
+        }
+
+        let (width, height) = self.properties.get_dimensions();
+        let (width, height) = (width as u32, height as u32);
+
+        if x >= width || y >= height {
+            return None;
+        }
+
+        let x = if self.mirror.is_horizontal() {
+            width - 1 - x
+        } else {
+            x
+        };
+        let y = if self.mirror.is_vertical() {
+            height - 1 - y
+        } else {
+            y
+        };
+
+        Some((x, y))
+    }
+
+    /// Check the display is reachable by sending it a harmless NOP command. Only I2C can detect
+    /// a disconnected display; see
+    /// [`DisplayProperties::ping`](../../properties/struct.DisplayProperties.html#method.ping).
+    pub fn ping(&mut self) -> Result<(), DI::Error> {
+        self.properties.ping()
+    }
+
+    /// Set the display contrast. Higher values are brighter.
+    pub fn set_contrast(&mut self, contrast: u8) -> Result<(), DI::Error> {
+        self.properties.set_contrast(contrast)
+    }
+
+    /// Tune the panel with one of the [`ImageQuality`](../../imagequality/enum.ImageQuality.html)
+    /// presets, setting the contrast, precharge period and VCOM deselect level together
+    pub fn apply_preset(&mut self, preset: ImageQuality) -> Result<(), DI::Error> {
+        self.properties.apply_preset(preset)
+    }
+
+    /// Get the current contrast setting. See
+    /// [`DisplayProperties::contrast`](../../properties/struct.DisplayProperties.html#method.contrast).
+    pub fn contrast(&self) -> u8 {
+        self.properties.contrast()
+    }
+
+    /// Set the mapping from light sensor readings to contrast used by
+    /// [`set_brightness_from_lux`](#method.set_brightness_from_lux)
+    pub fn set_lux_mapping(&mut self, lux_mapping: LuxMapping) {
+        self.properties.set_lux_mapping(lux_mapping)
+    }
+
+    /// Set the contrast from an ambient light sensor reading in lux
+    pub fn set_brightness_from_lux(&mut self, lux: u16) -> Result<(), DI::Error> {
+        self.properties.set_brightness_from_lux(lux)
+    }
+
+    /// Turn the display on or off. The display keeps its contents while off and the last set
+    /// contrast is restored when it is turned back on.
+    pub fn display_on(&mut self, on: bool) -> Result<(), DI::Error> {
+        self.properties.display_on(on)
+    }
+
+    /// Flush the buffer, then put the display in its lowest power state by turning it off and
+    /// disabling the charge pump. `delay` is used to let the last transfer settle before
+    /// powering down. Use [`wake_and_show`](#method.wake_and_show) to turn it back on.
+    pub fn flush_and_sleep<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), DI::Error>
+    where
+        DELAY: DelayMs<u8>,
+    {
+        self.flush()?;
+        delay.delay_ms(1);
+        self.properties.display_on(false)?;
+        self.properties.set_charge_pump(false)
+    }
+
+    /// Wake the display after [`flush_and_sleep`](#method.flush_and_sleep) and show the retained
+    /// buffer again. The panel is powered up with
+    /// [`power_on_sequence`](#method.power_on_sequence), so `delay` is used to wait for the
+    /// panel supply to settle before the display is turned on.
+    ///
+    /// ```rust
+    /// # use sh1106::mock::{self, MockDelay};
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    /// let mut delay = MockDelay::new();
+    ///
+    /// disp.wake_and_show(&mut delay).unwrap();
+    /// assert_eq!(delay.waited_ms(), 100);
+    ///
+    /// // Charge pump on, contrast, display on, then the frame
+    /// let iface = disp.release_interface();
+    /// assert_eq!(iface.commands()[..5], [0xAD, 0x8B, 0x81, 0x80, 0xAF]);
+    /// assert_eq!(iface.data_len(), 128 * 8);
+    /// ```
+    pub fn wake_and_show<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), DI::Error>
+    where
+        DELAY: DelayMs<u8>,
+    {
+        self.properties.power_on_sequence(delay)?;
+        self.flush()
+    }
+
+    /// Enable the charge pump, wait for the panel supply to settle and turn the display on. See
+    /// [`DisplayProperties::power_on_sequence`](../../properties/struct.DisplayProperties.html#method.power_on_sequence).
+    pub fn power_on_sequence<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), DI::Error>
+    where
+        DELAY: DelayMs<u8>,
+    {
+        self.properties.power_on_sequence(delay)
+    }
+
+    /// Turn the display off and disable the charge pump. See
+    /// [`DisplayProperties::shutdown`](../../properties/struct.DisplayProperties.html#method.shutdown).
+    pub fn shutdown(&mut self) -> Result<(), DI::Error> {
+        self.properties.shutdown()
+    }
+
+    /// Hand back the display interface, dropping the buffer. Use
+    /// [`into_parts`](#method.into_parts) to keep the buffer and display properties instead.
+    pub fn release_interface(self) -> DI {
+        self.properties.release()
+    }
+
+    /// Turn every pixel on regardless of the display buffer contents (`true`), or go back to
+    /// showing the last flushed buffer (`false`)
+    pub fn set_entire_on(&mut self, on: bool) -> Result<(), DI::Error> {
+        self.properties.set_entire_on(on)
+    }
+}
+
+impl<DI, BUF, CommE, PinE> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface<Error = Error<CommE, PinE>>,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Point the display RAM write pointer at screen column `x` of the page containing row `y`,
+    /// for rendering outside the buffer. See
+    /// [`DisplayProperties::goto`](../../properties/struct.DisplayProperties.html#method.goto).
+    /// The next `flush` moves the pointer back to the start of the frame.
+    pub fn goto(&mut self, x: u8, y: u8) -> Result<(), Error<CommE, PinE>> {
+        self.properties.goto(x, y)
+    }
+
+    /// Send the pages of the buffer covered by `region` to the display, whether they changed or
+    /// not, e.g. to update one zone of a dashboard at its own rate. Returns `Error::InvalidArea`
+    /// without sending anything if the region is empty or runs past the last page of the display.
+    /// Like [`flush`](#method.flush), the display is pointed back at the start of the frame
+    /// afterwards.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// # use sh1106::Error;
+    /// #
+    /// const GAUGE: Region = Region::new(0, 1);
+    /// const LOG: Region = Region::new(2, 3);
+    ///
+    /// let mut disp = mock::graphics(DisplaySize::Display128x32, DisplayRotation::Rotate0);
+    ///
+    /// assert!(disp.flush_region(GAUGE).is_ok());
+    /// assert!(disp.flush_region(LOG).is_ok());
+    ///
+    /// // A 128x32 display only has 4 pages
+    /// assert!(matches!(disp.flush_region(Region::new(2, 4)), Err(Error::InvalidArea)));
+    ///
+    /// assert_eq!(disp.release_interface().data_len(), 128 * 4);
+    /// ```
+    pub fn flush_region(&mut self, region: Region) -> Result<(), Error<CommE, PinE>> {
+        if !region.fits(self.properties.page_count()) {
+            return Err(Error::InvalidArea);
+        }
+
+        for page in region.start_page()..=region.end_page() {
+            if let Err(e) = self.flush_page(page) {
+                let _ = self.properties.reset_pointer();
+
+                return Err(e);
+            }
+
+            self.dirty &= !(1 << page);
+        }
+
+        self.properties.reset_pointer()
+    }
 }
 
 #[cfg(feature = "graphics")]
@@ -169,17 +1315,177 @@ extern crate embedded_graphics;
 #[cfg(feature = "graphics")]
 use self::embedded_graphics::{
     drawable,
+    fonts::{Font, Font6x8},
+    image::Image,
     pixelcolor::{
         raw::{RawData, RawU1},
         BinaryColor,
     },
+    prelude::{Point, Transform, WithStyle},
     Drawing,
 };
+#[cfg(feature = "graphics")]
+use crate::text::{text_width, CHAR_HEIGHT};
+
+#[cfg(feature = "graphics")]
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Draw `s` with its top left corner at `x, y` using the 6x8 pixel font. Each `\n` starts a
+    /// new line 8 pixels further down. Use [`text_width`](../../text/fn.text_width.html) to find
+    /// out how wide the text will be. Lines off the display are skipped.
+    pub fn draw_text(&mut self, s: &str, x: i32, y: i32, on: bool) {
+        let color = if on {
+            BinaryColor::On
+        } else {
+            BinaryColor::Off
+        };
+
+        let (width, height) = self.get_dimensions();
+
+        for (line_idx, line) in s.split('\n').enumerate() {
+            let line_y = y.saturating_add((line_idx as i32).saturating_mul(CHAR_HEIGHT as i32));
+
+            // The font adds pixel offsets to the position unchecked, so skip lines that are
+            // entirely off the display rather than let them overflow
+            if x >= i32::from(width)
+                || line_y >= i32::from(height)
+                || line_y.saturating_add(CHAR_HEIGHT as i32) <= 0
+            {
+                continue;
+            }
+
+            self.draw(
+                Font6x8::render_str(line)
+                    .stroke(Some(color))
+                    .translate(Point::new(x, line_y)),
+            );
+        }
+    }
+
+    /// Draw `s` like [`draw_text`](#method.draw_text), but set every pixel of each character cell:
+    /// glyph pixels according to `fg_on` and the rest of the cell according to `bg_on`. This
+    /// fully replaces whatever was under the text, so a field can be redrawn in place without
+    /// clearing it first. Parts of the text off the display are clipped.
+    ///
+    /// Only the cells of the new text are covered, so clear a field before redrawing it with
+    /// shorter text, or pad the text with spaces to a fixed width.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    ///
+    /// // Dark text on a lit background fills its whole cell
+    /// disp.draw_text_opaque("-", 0, 0, false, true);
+    /// assert!(disp.get_pixel(0, 0) && disp.get_pixel(5, 7) && !disp.get_pixel(6, 0));
+    ///
+    /// // Text far off the display is clipped
+    /// disp.draw_text_opaque("hi\nthere", i32::MAX - 1, i32::MAX - 1, true, true);
+    /// disp.draw_text_opaque("hi\nthere", 0, i32::MIN, true, true);
+    /// assert_eq!(disp.pixels().filter(|(_, _, on)| *on).count(), 6 * 8 - 5);
+    /// ```
+    pub fn draw_text_opaque(&mut self, s: &str, x: i32, y: i32, fg_on: bool, bg_on: bool) {
+        for (line_idx, line) in s.split('\n').enumerate() {
+            let line_y = y.saturating_add((line_idx as i32).saturating_mul(CHAR_HEIGHT as i32));
+            let right = x.saturating_add(text_width(line).min(i32::MAX as u32) as i32);
+            let bottom = line_y.saturating_add(CHAR_HEIGHT as i32);
+
+            // Clip the background to the right of and below the display origin
+            let (left, top) = (x.max(0), line_y.max(0));
+
+            if right > left && bottom > top {
+                self.fill_area(
+                    left as u32,
+                    top as u32,
+                    (right - left) as u32,
+                    (bottom - top) as u32,
+                    bg_on,
+                );
+            }
+
+            self.draw_text(line, x, line_y, fg_on);
+        }
+    }
+
+    /// Draw a 1 bit per pixel raw image, in the format used by embedded-graphics' `Image`, with
+    /// its top left corner at `x, y`. The height follows from the length of `data`; bytes past
+    /// the last whole row are ignored. Clear bits clear the display, so the image is opaque.
+    /// Parts of the image that fall off any edge of the display, including to the left or above
+    /// it, are clipped.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    ///
+    /// // Rows of a 12 pixel wide image take 2 bytes, so the third byte is left over
+    /// disp.draw_raw_image(&[0xFF; 3], 12, 0, 0);
+    ///
+    /// let lit: Vec<_> = disp.pixels().filter(|(_, _, on)| *on).map(|(x, y, _)| (x, y)).collect();
+    /// assert_eq!(lit, (0..12).map(|x| (x, 0)).collect::<Vec<_>>());
+    /// ```
+    pub fn draw_raw_image(&mut self, data: &[u8], width: u32, x: i32, y: i32) {
+        if width == 0 {
+            return;
+        }
+
+        let row_bytes = width.div_ceil(8) as usize;
+        let height = data.len() / row_bytes;
+
+        if height == 0 {
+            return;
+        }
+
+        // `Image` panics unless the data is a whole number of rows
+        let data = &data[..height * row_bytes];
+        let image: Image<BinaryColor> =
+            Image::new(data, width, height as u32).translate(Point::new(x, y));
+
+        self.draw(&image);
+    }
+
+    /// Draw `s` horizontally centered on the display with its top at `y`, taking into account the
+    /// current rotation. Each line of a multi-line string is centered on its own. Lines wider
+    /// than the display are centered too and lose the same number of pixels on both sides.
+    pub fn draw_text_centered(&mut self, s: &str, y: u32, on: bool) {
+        let (width, _) = self.properties.get_dimensions();
+
+        for (line_idx, line) in s.split('\n').enumerate() {
+            let x = (i32::from(width) - text_width(line) as i32) / 2;
+            let line_y = (y.min(i32::MAX as u32) as i32)
+                .saturating_add((line_idx as i32).saturating_mul(CHAR_HEIGHT as i32));
+
+            self.draw_text(line, x, line_y, on);
+        }
+    }
+}
 
+/// Pixels drawn with `BinaryColor::On` are set and pixels drawn with `BinaryColor::Off` are
+/// cleared, so shapes drawn in `Off` can be used to erase parts of the display.
+///
+/// ```rust
+/// # use embedded_graphics::{drawable::Pixel, pixelcolor::BinaryColor, prelude::*};
+/// # use sh1106::mock;
+/// # use sh1106::prelude::*;
+/// #
+/// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+///
+/// disp.draw(core::iter::once(Pixel(Point::new(3, 5), BinaryColor::On)));
+/// assert_eq!(disp.buffer()[3], 0b0010_0000);
+///
+/// disp.draw(core::iter::once(Pixel(Point::new(3, 5), BinaryColor::Off)));
+/// assert_eq!(disp.buffer()[3], 0);
+/// ```
 #[cfg(feature = "graphics")]
-impl<DI> Drawing<BinaryColor> for GraphicsMode<DI>
+impl<DI, BUF> Drawing<BinaryColor> for GraphicsMode<DI, BUF>
 where
     DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
 {
     fn draw<T>(&mut self, item_pixels: T)
     where
@@ -7,6 +7,7 @@
 use crate::interface::DisplayInterface;
 use crate::mode::displaymode::DisplayModeTrait;
 use crate::properties::DisplayProperties;
+use crate::Error;
 
 /// Raw display mode
 pub struct RawMode<DI>
@@ -36,4 +37,46 @@ impl<DI: DisplayInterface> RawMode<DI> {
     pub fn new(properties: DisplayProperties<DI>) -> Self {
         RawMode { properties }
     }
+
+    /// Initialise the display using the addressing mode set in the builder
+    pub fn init(&mut self) -> Result<(), DI::Error> {
+        self.properties.init()
+    }
+
+    /// Render the display one page at a time into a small scratch buffer instead of a full
+    /// framebuffer. See
+    /// [`DisplayProperties::render_by_page`](../../properties/struct.DisplayProperties.html#method.render_by_page)
+    /// for the coordinate convention.
+    pub fn render_by_page<F>(&mut self, f: F) -> Result<(), DI::Error>
+    where
+        F: FnMut(u8, &mut [u8]),
+    {
+        self.properties.render_by_page(f)
+    }
+
+    /// Stream a full frame to the display from an iterator of pixels. See
+    /// [`DisplayProperties::flush_from_iter`](../../properties/struct.DisplayProperties.html#method.flush_from_iter)
+    /// for the order pixels must come in.
+    pub fn flush_from_iter<I>(&mut self, pixels: I) -> Result<(), DI::Error>
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        self.properties.flush_from_iter(pixels)
+    }
+}
+
+impl<DI, CommE, PinE> RawMode<DI>
+where
+    DI: DisplayInterface<Error = Error<CommE, PinE>>,
+{
+    /// Write `data` straight to display RAM at `page`, starting at screen column `col`. See
+    /// [`DisplayProperties::write_data_at`](../../properties/struct.DisplayProperties.html#method.write_data_at).
+    pub fn write_data_at(
+        &mut self,
+        page: u8,
+        col: u8,
+        data: &[u8],
+    ) -> Result<(), Error<CommE, PinE>> {
+        self.properties.write_data_at(page, col, data)
+    }
 }
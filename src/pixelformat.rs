@@ -0,0 +1,47 @@
+//! Pixel packing in the display buffer
+//!
+//! [`GraphicsMode`](../mode/graphics/struct.GraphicsMode.html) keeps all knowledge of how pixels
+//! are stored in its buffer behind the [`PixelFormat`](trait.PixelFormat.html) trait. The SH1106
+//! is monochrome, so [`Mono`](struct.Mono.html) is the only format for now.
+
+/// How pixels are packed into the bytes of a display buffer. Coordinates are buffer coordinates,
+/// i.e. columns and rows of display RAM with rotation already applied.
+pub trait PixelFormat {
+    /// Number of bits each pixel takes in the buffer
+    const BITS_PER_PIXEL: u8;
+
+    /// Get the index of the byte holding the pixel at `column, row` in a buffer for a display
+    /// `width` columns wide, and the mask of the bits within that byte that belong to the pixel
+    fn locate(column: usize, row: usize, width: usize) -> (usize, u8);
+
+    /// Store `value` in the bits of `byte` selected by `mask`, returning the new byte
+    fn pack(byte: u8, mask: u8, value: u8) -> u8;
+
+    /// Read the value stored in the bits of `byte` selected by `mask`
+    fn unpack(byte: u8, mask: u8) -> u8;
+}
+
+/// One bit per pixel, as used by the SH1106's display RAM. Each byte holds a column of 8 pixels
+/// within a page, with the least significant bit at the top. Any non-zero value is on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mono;
+
+impl PixelFormat for Mono {
+    const BITS_PER_PIXEL: u8 = 1;
+
+    fn locate(column: usize, row: usize, width: usize) -> (usize, u8) {
+        (row / 8 * width + column, 1 << (row % 8))
+    }
+
+    fn pack(byte: u8, mask: u8, value: u8) -> u8 {
+        if value == 0 {
+            byte & !mask
+        } else {
+            byte | mask
+        }
+    }
+
+    fn unpack(byte: u8, mask: u8) -> u8 {
+        (byte & mask != 0) as u8
+    }
+}
@@ -1,6 +1,23 @@
 //! Crate prelude
+//!
+//! Drawing primitives such as `draw_line` and the widgets are methods on
+//! [`GraphicsMode`](../mode/graphics/struct.GraphicsMode.html), so they are available as soon as
+//! it is in scope. Items that only exist with a feature enabled are only exported with it.
 
+pub use super::addressmode::AddressMode;
+pub use super::bitorder::BitOrder;
+pub use super::blendop::BlendOp;
+pub use super::builder::Builder;
+pub use super::displayconfig::DisplayConfig;
 pub use super::displayrotation::DisplayRotation;
 pub use super::displaysize::DisplaySize;
+pub use super::imagequality::ImageQuality;
 pub use super::interface::{I2cInterface, SpiInterface};
+pub use super::mirror::Mirror;
 pub use super::mode::GraphicsMode;
+pub use super::region::Region;
+pub use super::text::text_width;
+#[cfg(feature = "widgets")]
+pub use super::widgets::seven_segment_size;
+#[cfg(all(feature = "widgets", feature = "graphics"))]
+pub use super::widgets::{Marquee, Menu};
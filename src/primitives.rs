@@ -0,0 +1,167 @@
+//! Basic shapes for [`GraphicsMode`](../mode/graphics/struct.GraphicsMode.html) that don't need
+//! the `graphics` feature
+//!
+//! Like the other drawing methods, these take the current display rotation into account and
+//! ignore pixels outside the display, so shapes running off the edge are clipped.
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Draw a one pixel wide line from `x0, y0` to `x1, y1`, both ends included, setting or
+    /// clearing its pixels according to `on`, using Bresenham's algorithm. The line is clipped to
+    /// the display before it is drawn, so lines ending far off screen are as cheap as short ones.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    /// disp.draw_line(0, 0, 6, 2, true);
+    ///
+    /// let lit: Vec<_> = disp.pixels().filter(|(_, _, on)| *on).map(|(x, y, _)| (x, y)).collect();
+    /// assert_eq!(lit, [(0, 0), (1, 0), (2, 1), (3, 1), (4, 1), (5, 2), (6, 2)]);
+    ///
+    /// // Lines running far off the display are clipped to it
+    /// disp.clear();
+    /// disp.draw_line(0, 5, u32::MAX, 5, true);
+    /// disp.draw_line(0, 0, u32::MAX, 40, true);
+    /// assert_eq!(disp.pixels().filter(|(_, _, on)| *on).count(), 2 * 128);
+    /// ```
+    pub fn draw_line(&mut self, x0: u32, y0: u32, x1: u32, y1: u32, on: bool) {
+        if y0 == y1 {
+            // Horizontal lines set whole runs at once
+            let len = (x0.max(x1) - x0.min(x1)).saturating_add(1);
+
+            self.draw_pixel_run(x0.min(x1), y0, len, on);
+            return;
+        }
+
+        self.draw_line_clipped(
+            (i64::from(x0), i64::from(y0)),
+            (i64::from(x1), i64::from(y1)),
+            on,
+        );
+    }
+
+    /// Draw a line `width` pixels thick from `x0, y0` to `x1, y1`, e.g. for gauge needles. The
+    /// line is made of parallel one pixel lines offset vertically for lines closer to horizontal
+    /// and horizontally for lines closer to vertical, centered on the line between the two
+    /// points. A `width` of 0 draws nothing and 1 is the same as `draw_line`.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    ///
+    /// // A vertical line 3 pixels wide, clipped at the left edge
+    /// disp.draw_thick_line(0, 10, 0, 19, 3, true);
+    /// assert_eq!(disp.pixels().filter(|(_, _, on)| *on).count(), 2 * 10);
+    /// assert!(disp.get_pixel(1, 10) && !disp.get_pixel(2, 10));
+    ///
+    /// // Lines wider than the display and running far off it only draw what is visible
+    /// disp.clear();
+    /// disp.draw_thick_line(0, 32, u32::MAX, 32, u32::MAX, true);
+    /// assert_eq!(disp.pixels().filter(|(_, _, on)| *on).count(), 128 * 64);
+    /// ```
+    pub fn draw_thick_line(&mut self, x0: u32, y0: u32, x1: u32, y1: u32, width: u32, on: bool) {
+        if width == 0 {
+            return;
+        }
+
+        let (x0, y0, x1, y1) = (i64::from(x0), i64::from(y0), i64::from(x1), i64::from(y1));
+        let mostly_horizontal = (x1 - x0).abs() >= (y1 - y0).abs();
+        let width = i64::from(width);
+
+        // Only offsets that move part of the line onto the display are drawn
+        let (display_width, display_height) = self.get_dimensions();
+        let (near, far, limit) = if mostly_horizontal {
+            (y0.min(y1), y0.max(y1), i64::from(display_height))
+        } else {
+            (x0.min(x1), x0.max(x1), i64::from(display_width))
+        };
+        let first = (-(width - 1) / 2).max(-far);
+        let last = (width / 2).min(limit - 1 - near);
+
+        for offset in first..=last {
+            let (dx, dy) = if mostly_horizontal {
+                (0, offset)
+            } else {
+                (offset, 0)
+            };
+
+            self.draw_line_clipped((x0 + dx, y0 + dy), (x1 + dx, y1 + dy), on);
+        }
+    }
+
+    /// Draw the pixels Bresenham's algorithm picks for the line from `start` to `end`, only
+    /// visiting the steps that land on the display. Each step moves one pixel along the longer
+    /// axis and the other coordinate is rounded from the exact line, so the visible steps can be
+    /// found up front however far off screen the ends are.
+    fn draw_line_clipped(&mut self, start: (i64, i64), end: (i64, i64), on: bool) {
+        let value = on as u8;
+        let (width, height) = self.get_dimensions();
+
+        let (x0, y0) = (i128::from(start.0), i128::from(start.1));
+        let (dx, dy) = (i128::from(end.0) - x0, i128::from(end.1) - y0);
+        let (step_x, step_y) = (dx.signum(), dy.signum());
+        let (dx, dy) = (dx.abs(), dy.abs());
+        let steps = dx.max(dy);
+
+        let (first_x, last_x) = visible_steps(x0, step_x, dx, steps, i128::from(width));
+        let (first_y, last_y) = visible_steps(y0, step_y, dy, steps, i128::from(height));
+
+        for step in first_x.max(first_y)..=last_x.min(last_y) {
+            let x = x0 + step_x * line_offset(step, dx, steps);
+            let y = y0 + step_y * line_offset(step, dy, steps);
+
+            self.set_pixel(x as u32, y as u32, value);
+        }
+    }
+}
+
+/// Distance moved along an axis that changes by `delta` over a line `steps` long after `step`
+/// steps, rounded to the nearest pixel with halves rounded up
+fn line_offset(step: i128, delta: i128, steps: i128) -> i128 {
+    if steps == 0 {
+        return 0;
+    }
+
+    (2 * step * delta + steps).div_euclid(2 * steps)
+}
+
+/// First and last step of a line `steps` long at which the coordinate starting at `start` and
+/// moving `delta` in the direction of `sign` lies within `0..limit`. The range is empty if the
+/// line never does.
+fn visible_steps(start: i128, sign: i128, delta: i128, steps: i128, limit: i128) -> (i128, i128) {
+    // Offsets from `start` that are on the display
+    let (low, high) = if sign < 0 {
+        (start - (limit - 1), start)
+    } else {
+        (-start, limit - 1 - start)
+    };
+
+    if delta == 0 {
+        return if low <= 0 && high >= 0 {
+            (0, steps)
+        } else {
+            (1, 0)
+        };
+    }
+
+    // Invert `line_offset`: the first step reaching `low` and the last one before `high + 1`
+    let first = div_ceil(2 * steps * low - steps, 2 * delta);
+    let last = div_ceil(2 * steps * (high + 1) - steps, 2 * delta) - 1;
+
+    (first.max(0), last.min(steps))
+}
+
+/// Division rounding towards positive infinity, for a positive divisor
+fn div_ceil(numerator: i128, divisor: i128) -> i128 {
+    -(-numerator).div_euclid(divisor)
+}
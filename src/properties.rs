@@ -1,19 +1,105 @@
 //! Container to store and set display properties
 
-use crate::command::{Command, VcomhLevel};
+use hal::blocking::delay::DelayMs;
+
+use crate::addressmode::AddressMode;
+use crate::command::{Command, Page};
 use crate::displayrotation::DisplayRotation;
 use crate::displaysize::DisplaySize;
-use crate::interface::DisplayInterface;
+use crate::imagequality::ImageQuality;
+use crate::interface::{DisplayInterface, ReadableInterface};
+use crate::Error;
+
+/// Configuration sent to the display during initialisation, returned by
+/// [`DisplayProperties::init_verbose`](struct.DisplayProperties.html#method.init_verbose)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InitReport {
+    /// Configured display size
+    pub display_size: DisplaySize,
+    /// Configured display rotation
+    pub display_rotation: DisplayRotation,
+    /// Column offset applied when drawing
+    pub column_offset: u8,
+    /// Whether the command to enable the charge pump was sent
+    pub charge_pump: bool,
+}
+
+/// Mapping from an ambient light reading to a display contrast, used by
+/// [`DisplayProperties::set_brightness_from_lux`](struct.DisplayProperties.html#method.set_brightness_from_lux)
+///
+/// Readings at or below `min_lux` give `min_contrast`, readings at or above `max_lux` give
+/// `max_contrast`, and readings in between are interpolated linearly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LuxMapping {
+    /// Light level at and below which the minimum contrast is used
+    pub min_lux: u16,
+    /// Light level at and above which the maximum contrast is used
+    pub max_lux: u16,
+    /// Contrast used in the dark
+    pub min_contrast: u8,
+    /// Contrast used in bright light
+    pub max_contrast: u8,
+}
+
+impl Default for LuxMapping {
+    fn default() -> Self {
+        Self {
+            min_lux: 10,
+            max_lux: 1000,
+            min_contrast: 0x10,
+            max_contrast: 0xFF,
+        }
+    }
+}
+
+impl LuxMapping {
+    /// Get the contrast for a light level of `lux`
+    ///
+    /// ```rust
+    /// # use sh1106::properties::LuxMapping;
+    /// let mapping = LuxMapping::default();
+    ///
+    /// assert_eq!(mapping.contrast(0), 0x10);
+    /// assert_eq!(mapping.contrast(5000), 0xFF);
+    /// ```
+    pub fn contrast(&self, lux: u16) -> u8 {
+        if lux <= self.min_lux || self.max_lux <= self.min_lux {
+            return self.min_contrast;
+        }
+
+        if lux >= self.max_lux {
+            return self.max_contrast;
+        }
+
+        let lux_range = i32::from(self.max_lux - self.min_lux);
+        let contrast_range = i32::from(self.max_contrast) - i32::from(self.min_contrast);
+        let offset = i32::from(lux - self.min_lux) * contrast_range / lux_range;
+
+        (i32::from(self.min_contrast) + offset) as u8
+    }
+}
 
 /// Display properties struct
-pub struct DisplayProperties<DI> {
-    iface: DI,
+pub struct DisplayProperties<DI>
+where
+    DI: DisplayInterface,
+{
+    // Only `None` once the interface has been handed back by `release`
+    iface: Option<DI>,
     display_size: DisplaySize,
     display_rotation: DisplayRotation,
+    address_mode: AddressMode,
+    contrast: u8,
+    inverted: bool,
+    lux_mapping: LuxMapping,
     draw_area_start: (u8, u8),
     draw_area_end: (u8, u8),
     draw_column: u8,
     draw_row: u8,
+    clear_on_drop: bool,
+    clear_on_init: bool,
+    init_delay_ms: u8,
+    init_nops: u8,
 }
 
 impl<DI> DisplayProperties<DI>
@@ -27,47 +113,232 @@ where
         display_rotation: DisplayRotation,
     ) -> DisplayProperties<DI> {
         DisplayProperties {
-            iface,
+            iface: Some(iface),
             display_size,
             display_rotation,
+            address_mode: AddressMode::Column,
+            contrast: 0x80,
+            inverted: false,
+            lux_mapping: LuxMapping::default(),
             draw_area_start: (0, 0),
             draw_area_end: (0, 0),
             draw_column: 0,
             draw_row: 0,
+            clear_on_drop: false,
+            clear_on_init: false,
+            init_delay_ms: 0,
+            init_nops: 0,
+        }
+    }
+
+    /// Turn the display off, then disable the charge pump, leaving the panel unpowered with the
+    /// display RAM retained. The display must be off before the charge pump is disabled, so
+    /// don't reorder these steps when powering down by hand. Reinitialise the display or use
+    /// `power_on_sequence` to turn it back on.
+    pub fn shutdown(&mut self) -> Result<(), DI::Error> {
+        self.display_on(false)?;
+        self.set_charge_pump(false)
+    }
+
+    /// Hand back the display interface, e.g. to share the bus with other devices after a
+    /// `shutdown`. The display is left as it is; clear on drop doesn't apply.
+    pub fn release(mut self) -> DI {
+        self.iface.take().expect("Interface already released")
+    }
+
+    fn iface(&mut self) -> &mut DI {
+        self.iface.as_mut().expect("Interface already released")
+    }
+
+    /// Blank the display and turn it off when this instance is dropped. Errors that occur while
+    /// doing so are ignored. Off by default.
+    pub fn set_clear_on_drop(&mut self, clear_on_drop: bool) {
+        self.clear_on_drop = clear_on_drop;
+    }
+
+    /// Clear display RAM during `init`, before the display is turned on, so no random RAM
+    /// contents are shown before the first flush. Off by default, so display RAM that survived a
+    /// reset without a power cycle is shown again once the display is turned on.
+    pub fn set_clear_on_init(&mut self, clear_on_init: bool) {
+        self.clear_on_init = clear_on_init;
+    }
+
+    /// Wait `init_delay_ms` milliseconds between enabling the charge pump and the rest of the
+    /// initialisation, for panels that stay blank if they are set up too quickly. The delay is
+    /// only applied by [`init_with_delay`](#method.init_with_delay), as it needs a delay
+    /// provider. Defaults to 0.
+    pub fn set_init_delay_ms(&mut self, init_delay_ms: u8) {
+        self.init_delay_ms = init_delay_ms;
+    }
+
+    /// Send `init_nops` NOP commands after every command of the initialisation sequence, giving
+    /// the controller time to settle between commands. Some SH1106 clones, often sold as
+    /// 1.3" modules, end up with a corrupted configuration if init commands arrive back to back
+    /// on a fast bus; a handful of NOPs is usually enough. Defaults to 0. The RAM clear done by
+    /// `init` when enabled is mostly display data and isn't spaced out.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut properties = mock::properties(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    /// properties.set_init_nops(1);
+    /// properties.init().unwrap();
+    ///
+    /// let iface = properties.release();
+    /// // Display off, NOP, clock divider, NOP, ...
+    /// assert_eq!(iface.commands()[..5], [0xAE, 0xE3, 0xD5, 0x80, 0xE3]);
+    /// // Segment remap and COM scan direction are spaced out too
+    /// assert!(iface.commands().windows(4).any(|w| w == [0xA1, 0xE3, 0xC8, 0xE3]));
+    /// ```
+    pub fn set_init_nops(&mut self, init_nops: u8) {
+        self.init_nops = init_nops;
+    }
+
+    /// Reset the display using the reset pin managed by the interface, if any
+    pub fn reset<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), DI::Error>
+    where
+        DELAY: DelayMs<u8>,
+    {
+        self.iface().reset(delay)
+    }
+
+    /// Initialise the display using the configured [`AddressMode`]. The multiplex ratio comes
+    /// from the configured display size, so smaller panels only drive the rows they have.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// for (size, multiplex) in [
+    ///     (DisplaySize::Display128x64, 63),
+    ///     (DisplaySize::Display128x32, 31),
+    ///     (DisplaySize::Display132x64, 63),
+    /// ] {
+    ///     let mut properties = mock::properties(size, DisplayRotation::Rotate0);
+    ///     properties.init().unwrap();
+    ///
+    ///     let iface = properties.release();
+    ///     // Multiplex ratio, then a display offset of 0
+    ///     assert!(iface.commands().windows(4).any(|w| w == [0xA8, multiplex, 0xD3, 0]));
+    /// }
+    /// ```
+    pub fn init(&mut self) -> Result<(), DI::Error> {
+        match self.address_mode {
+            AddressMode::Column => self.init_column_mode(),
+            AddressMode::Page => self.init_page_mode(),
         }
     }
 
     /// Initialise the display in column mode (i.e. a byte walks down a column of 8 pixels) with
-    /// column 0 on the left and column _(display_width - 1)_ on the right.
+    /// column 0 on the left and column _(display_width - 1)_ on the right. Calls to `draw` move
+    /// on to the next page when the end of the draw area is reached.
     pub fn init_column_mode(&mut self) -> Result<(), DI::Error> {
-        self.iface.init()?;
+        self.address_mode = AddressMode::Column;
+        self.init_display(None)
+    }
+
+    /// Initialise the display in page mode. The panel is configured the same way as in column
+    /// mode, but calls to `draw` wrap back to the start of the current page when the end of the
+    /// draw area is reached. Use `set_draw_area` to select the page to write to.
+    pub fn init_page_mode(&mut self) -> Result<(), DI::Error> {
+        self.address_mode = AddressMode::Page;
+        self.init_display(None)
+    }
+
+    /// Initialise the display like [`init`](#method.init), waiting for the delay set with
+    /// [`set_init_delay_ms`](#method.set_init_delay_ms) after enabling the charge pump
+    pub fn init_with_delay<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), DI::Error>
+    where
+        DELAY: DelayMs<u8>,
+    {
+        self.init_display(Some(delay))
+    }
+
+    /// Initialise the display like [`init`](#method.init) and report the configuration that was
+    /// sent to it. Useful to check the driver is set up as intended during bring-up.
+    pub fn init_verbose(&mut self) -> Result<InitReport, DI::Error> {
+        self.init()?;
+
+        Ok(InitReport {
+            display_size: self.display_size,
+            display_rotation: self.display_rotation,
+            column_offset: self.display_size.column_offset(),
+            charge_pump: true,
+        })
+    }
+
+    fn init_display(&mut self, delay: Option<&mut dyn DelayMs<u8>>) -> Result<(), DI::Error> {
+        self.iface().init()?;
         // TODO: Break up into nice bits so display modes can pick whathever they need
-        let (_, display_height) = self.display_size.dimensions();
-        let display_rotation = self.display_rotation;
+        let display_size = self.display_size;
+
+        self.send_init_command(Command::DisplayOn(false))?;
+        self.send_init_command(Command::DisplayClockDiv(0x8, 0x0))?;
+        self.send_init_command(Command::Multiplex(display_size.multiplex_ratio()))?;
+        self.send_init_command(Command::DisplayOffset(0))?;
+
+        if self.display_size.is_sh1107() {
+            // The SH1107 needs a wider start line command to cover 128 rows. It also supports
+            // vertical addressing, but page addressing is selected so the same page by page
+            // drawing logic can be used for both controllers.
+            self.send_init_command(Command::StartLineExt(0))?;
+            self.send_init_command(Command::VerticalAddressing(false))?;
+        } else {
+            self.send_init_command(Command::StartLine(0))?;
+        }
 
-        Command::DisplayOn(false).send(&mut self.iface)?;
-        Command::DisplayClockDiv(0x8, 0x0).send(&mut self.iface)?;
-        Command::Multiplex(display_height - 1).send(&mut self.iface)?;
-        Command::DisplayOffset(0).send(&mut self.iface)?;
-        Command::StartLine(0).send(&mut self.iface)?;
         // TODO: Ability to turn charge pump on/off
         // Display must be off when performing this command
-        Command::ChargePump(true).send(&mut self.iface)?;
+        self.send_init_command(Command::ChargePump(true))?;
 
-        self.set_rotation(display_rotation)?;
+        if let Some(delay) = delay {
+            if self.init_delay_ms > 0 {
+                delay.delay_ms(self.init_delay_ms);
+            }
+        }
+
+        let (remap, reverse) = self.rotation_commands();
+        self.send_init_command(remap)?;
+        self.send_init_command(reverse)?;
 
         match self.display_size {
-            DisplaySize::Display128x32 => Command::ComPinConfig(false).send(&mut self.iface),
-            DisplaySize::Display128x64 => Command::ComPinConfig(true).send(&mut self.iface),
-            DisplaySize::Display132x64 => Command::ComPinConfig(true).send(&mut self.iface),
+            DisplaySize::Display128x32 => {
+                self.send_init_command(Command::ComPinConfig(false, false))
+            }
+            DisplaySize::Display128x64 => {
+                self.send_init_command(Command::ComPinConfig(true, false))
+            }
+            DisplaySize::Display132x64 => {
+                self.send_init_command(Command::ComPinConfig(true, false))
+            }
+            // The SH1107 has no COM pin configuration command
+            #[cfg(feature = "sh1107")]
+            DisplaySize::Display128x128 => Ok(()),
         }?;
 
-        Command::Contrast(0x80).send(&mut self.iface)?;
-        Command::PreChargePeriod(0x1, 0xF).send(&mut self.iface)?;
-        Command::VcomhDeselect(VcomhLevel::Auto).send(&mut self.iface)?;
-        Command::AllOn(false).send(&mut self.iface)?;
-        Command::Invert(false).send(&mut self.iface)?;
-        Command::DisplayOn(true).send(&mut self.iface)?;
+        self.send_init_command(Command::Contrast(self.contrast))?;
+        self.send_init_command(Command::PreChargePeriod(0x1, 0xF))?;
+        self.send_init_command(Command::VcomhDeselect(0x40))?;
+        self.send_init_command(Command::AllOn(false))?;
+        self.send_init_command(Command::Invert(self.inverted))?;
+
+        if self.clear_on_init {
+            self.clear_ram()?;
+        }
+
+        self.send_init_command(Command::DisplayOn(true))?;
+
+        Ok(())
+    }
+
+    /// Send a command during initialisation, followed by the configured number of NOPs
+    fn send_init_command(&mut self, command: Command) -> Result<(), DI::Error> {
+        command.send(self.iface())?;
+
+        for _ in 0..self.init_nops {
+            Command::Noop.send(self.iface())?;
+        }
 
         Ok(())
     }
@@ -90,15 +361,17 @@ where
     pub fn draw(&mut self, mut buffer: &[u8]) -> Result<(), DI::Error> {
         while buffer.len() > 0 {
             let count = self.draw_area_end.0 - self.draw_column;
-            self.iface.send_data(&buffer[..count as usize])?;
+            self.iface().send_data(&buffer[..count as usize])?;
             self.draw_column += count;
 
             if self.draw_column >= self.draw_area_end.0 {
                 self.draw_column = self.draw_area_start.0;
 
-                self.draw_row += 8;
-                if self.draw_row >= self.draw_area_end.1 {
-                    self.draw_row = self.draw_area_start.1;
+                if self.address_mode == AddressMode::Column {
+                    self.draw_row += 8;
+                    if self.draw_row >= self.draw_area_end.1 {
+                        self.draw_row = self.draw_area_start.1;
+                    }
                 }
 
                 self.send_draw_address()?;
@@ -110,10 +383,282 @@ where
         Ok(())
     }
 
+    /// Render the display one page at a time without a full framebuffer. For each page, `f` is
+    /// called with the page number and a zeroed scratch buffer one page tall and as wide as the
+    /// display, which is sent to the display as soon as `f` returns.
+    ///
+    /// Page `n` covers rows `n * 8` to `n * 8 + 7`. Each byte in the scratch buffer is one column
+    /// of the page, with the least significant bit being the top row. Rotation is not applied.
+    pub fn render_by_page<F>(&mut self, mut f: F) -> Result<(), DI::Error>
+    where
+        F: FnMut(u8, &mut [u8]),
+    {
+        let (display_width, _) = self.display_size.dimensions();
+        let column_offset = self.display_size.column_offset();
+
+        let mut scratch = [0; 132];
+        let scratch = &mut scratch[..display_width as usize];
+
+        for page in 0..self.page_count() {
+            scratch.iter_mut().for_each(|byte| *byte = 0);
+            f(page, scratch);
+
+            let row = page * 8;
+            self.set_draw_area(
+                (column_offset, row),
+                (display_width + column_offset, row + 8),
+            )?;
+            self.draw(scratch)?;
+        }
+
+        Ok(())
+    }
+
+    /// Stream a full frame to the display from an iterator of pixels, `true` being on, without a
+    /// framebuffer. Pixels must come in the order they are stored in display RAM:
+    ///
+    /// 1. Page by page from the top, page `n` covering rows `n * 8` to `n * 8 + 7`,
+    /// 2. within a page, column by column from the left of the screen,
+    /// 3. within a column, the 8 rows of the page from top to bottom.
+    ///
+    /// So for a 128 pixel wide display the first 8 items are column 0, rows 0 to 7, and item 1024
+    /// is column 0, row 8. Rotation is not applied. If the iterator runs out early, the rest of
+    /// the display is turned off; extra items are ignored.
+    pub fn flush_from_iter<I>(&mut self, pixels: I) -> Result<(), DI::Error>
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let mut pixels = pixels.into_iter();
+
+        self.render_by_page(|_, page| {
+            for byte in page.iter_mut() {
+                for bit in 0..8 {
+                    if pixels.next() == Some(true) {
+                        *byte |= 1 << bit;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Send a full frame laid out page by page, like a `GraphicsMode` buffer, using the SH1107's
+    /// vertical addressing. Each column's page bytes are consecutive in display RAM in this
+    /// mode, so the frame goes out as one stream after a single address setup. Columns are
+    /// batched up so the interface sees as few transfers as in page addressing. Page addressing is
+    /// restored afterwards. Must only be called for SH1107 displays.
+    pub(crate) fn draw_vertical(&mut self, buffer: &[u8]) -> Result<(), DI::Error> {
+        let (display_width, _) = self.display_size.dimensions();
+        let width = display_width as usize;
+        let pages = self.page_count() as usize;
+
+        self.reset_pointer()?;
+        Command::VerticalAddressing(true).send(self.iface())?;
+
+        let mut scratch = [0; 128];
+        let mut len = 0;
+
+        for column in 0..width {
+            for page in 0..pages {
+                scratch[len] = buffer[page * width + column];
+                len += 1;
+            }
+
+            if len + pages > scratch.len() {
+                self.iface().send_data(&scratch[..len])?;
+                len = 0;
+            }
+        }
+
+        self.iface().send_data(&scratch[..len])?;
+
+        Command::VerticalAddressing(false).send(self.iface())
+    }
+
+    /// Write zeroes to the whole visible area of display RAM
+    fn clear_ram(&mut self) -> Result<(), DI::Error> {
+        self.render_by_page(|_, _| ())
+    }
+
     fn send_draw_address(&mut self) -> Result<(), DI::Error> {
-        Command::PageAddress(self.draw_row.into()).send(&mut self.iface)?;
-        Command::ColumnAddressLow(0xF & self.draw_column).send(&mut self.iface)?;
-        Command::ColumnAddressHigh(0xF & (self.draw_column >> 4)).send(&mut self.iface)
+        self.send_page_address(self.draw_row / 8)?;
+        self.set_column_address(self.draw_column)
+    }
+
+    /// Point the display RAM write pointer at page 0 and the first column of the screen, with the
+    /// column offset applied, and make the whole screen the draw area. Use this to get back to a
+    /// known state after positioning the pointer by hand with `goto`, `set_page_address` or
+    /// `set_column_address`.
+    pub fn reset_pointer(&mut self) -> Result<(), DI::Error> {
+        let (display_width, display_height) = self.display_size.dimensions();
+        let column_offset = self.display_size.column_offset();
+
+        self.set_draw_area(
+            (column_offset, 0),
+            (display_width + column_offset, display_height),
+        )
+    }
+
+    /// Set the display RAM column the next data byte is written to, using the split low
+    /// (`0x00`-`0x0F`) and high (`0x10`-`0x1F`) nibble commands. The column advances by one with
+    /// every data byte sent.
+    ///
+    /// This is a RAM column, not a screen column: panels narrower than the controller's 132
+    /// column RAM are wired to the middle of it, so add
+    /// [`DisplaySize::column_offset`](../prelude/enum.DisplaySize.html#method.column_offset)
+    /// to get to the left edge of the screen. The draw area set by `set_draw_area` is not
+    /// updated, so don't mix this with `draw`.
+    pub fn set_column_address(&mut self, column: u8) -> Result<(), DI::Error> {
+        Command::ColumnAddressLow(0xF & column).send(self.iface())?;
+        Command::ColumnAddressHigh(0xF & (column >> 4)).send(self.iface())
+    }
+
+    /// Send the page address command without checking the page against the display size
+    fn send_page_address(&mut self, page: u8) -> Result<(), DI::Error> {
+        Command::PageAddress(Page::from(page.saturating_mul(8))).send(self.iface())
+    }
+
+    /// Set the display contrast. Higher values are brighter. The value is remembered and sent
+    /// again whenever the display is turned back on, as some modules reset it when switched off.
+    pub fn set_contrast(&mut self, contrast: u8) -> Result<(), DI::Error> {
+        self.contrast = contrast;
+
+        Command::Contrast(contrast).send(self.iface())
+    }
+
+    /// Set the pre-charge and discharge periods used to drive each row, in display clocks from 1
+    /// to 15. Longer pre-charge periods make pixels brighter but draw more current. `init` uses
+    /// a pre-charge period of 1 and a discharge period of 15.
+    pub fn set_precharge_period(&mut self, precharge: u8, discharge: u8) -> Result<(), DI::Error> {
+        Command::PreChargePeriod(precharge.clamp(1, 15), discharge.clamp(1, 15)).send(self.iface())
+    }
+
+    /// Set the VCOM deselect level, which sets how hard unlit pixels are held off. `0x00` is the
+    /// lowest level at 0.43 times the reference voltage, `0x40` and above the highest. Lower
+    /// levels can reduce ghosting and power draw but also brightness. `init` uses `0x40`.
+    pub fn set_vcom_deselect_level(&mut self, level: u8) -> Result<(), DI::Error> {
+        Command::VcomhDeselect(level).send(self.iface())
+    }
+
+    /// Set the COM pins hardware configuration with command `0xDA`. The second byte of the
+    /// command is `0b00RA_0010`, where `A` selects the alternative (1) or sequential (0) COM pin
+    /// layout and `R` swaps the left and right COM pins. The SH1106 datasheet only defines `A`;
+    /// `R` comes from the SSD1306 and is ignored by genuine SH1106 controllers. A layout that
+    /// doesn't match the panel wiring shows content on every other row only. `init` uses the
+    /// alternative layout for 64 pixel tall displays and the sequential layout for 128x32
+    /// displays, without remapping. The SH1107 has no such command, so this does nothing there.
+    pub fn set_com_pin_config(
+        &mut self,
+        alternative: bool,
+        left_right_remap: bool,
+    ) -> Result<(), DI::Error> {
+        if self.display_size.is_sh1107() {
+            return Ok(());
+        }
+
+        Command::ComPinConfig(alternative, left_right_remap).send(self.iface())
+    }
+
+    /// Set the contrast, precharge period and VCOM deselect level to one of the
+    /// [`ImageQuality`](../imagequality/enum.ImageQuality.html) presets
+    pub fn apply_preset(&mut self, preset: ImageQuality) -> Result<(), DI::Error> {
+        let (precharge, discharge) = preset.precharge_period();
+
+        self.set_precharge_period(precharge, discharge)?;
+        self.set_vcom_deselect_level(preset.vcom_deselect_level())?;
+        self.set_contrast(preset.contrast())
+    }
+
+    /// Get the contrast last set with `set_contrast` or `set_brightness_from_lux`, or the value
+    /// sent by `init` if it hasn't been changed. This is the cached value; the display itself
+    /// can't be read back over the serial interfaces.
+    pub fn contrast(&self) -> u8 {
+        self.contrast
+    }
+
+    /// Set the mapping used by [`set_brightness_from_lux`](#method.set_brightness_from_lux)
+    pub fn set_lux_mapping(&mut self, lux_mapping: LuxMapping) {
+        self.lux_mapping = lux_mapping;
+    }
+
+    /// Set the contrast from an ambient light sensor reading in lux, using the configured
+    /// [`LuxMapping`](struct.LuxMapping.html)
+    pub fn set_brightness_from_lux(&mut self, lux: u16) -> Result<(), DI::Error> {
+        let contrast = self.lux_mapping.contrast(lux);
+
+        self.set_contrast(contrast)
+    }
+
+    /// Turn the display on or off. The display RAM is retained while the display is off. The last
+    /// contrast set with `set_contrast` is reapplied when turning the display on.
+    pub fn display_on(&mut self, on: bool) -> Result<(), DI::Error> {
+        if on {
+            Command::Contrast(self.contrast).send(self.iface())?;
+        }
+
+        Command::DisplayOn(on).send(self.iface())
+    }
+
+    /// Check the display is reachable by sending it a NOP command, which has no effect on the
+    /// display. Over I2C a missing display doesn't acknowledge its address, so this returns an
+    /// error. SPI is write only, so over SPI this only detects errors raised by the SPI peripheral
+    /// itself and can't tell whether a display is connected.
+    pub fn ping(&mut self) -> Result<(), DI::Error> {
+        Command::Noop.send(self.iface())
+    }
+
+    /// Invert the display, so pixels that are on in display RAM are shown dark and vice versa. The
+    /// setting is remembered and sent again by `init`.
+    pub fn set_invert(&mut self, inverted: bool) -> Result<(), DI::Error> {
+        self.inverted = inverted;
+
+        Command::Invert(inverted).send(self.iface())
+    }
+
+    /// Whether the display is inverted, as last set with `set_invert`
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// Enable or disable the internal charge pump (DC-DC converter). The display should be off
+    /// when changing this setting.
+    pub fn set_charge_pump(&mut self, enable: bool) -> Result<(), DI::Error> {
+        Command::ChargePump(enable).send(self.iface())
+    }
+
+    /// Power the panel up gently: enable the charge pump, wait 100ms for the panel supply to
+    /// stabilise as recommended in the datasheet, then turn the display on. Turning the display
+    /// on straight after the pump, as `init` does, can cause a visible flash on some panels.
+    pub fn power_on_sequence<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), DI::Error>
+    where
+        DELAY: DelayMs<u8>,
+    {
+        self.set_charge_pump(true)?;
+        delay.delay_ms(100);
+        self.display_on(true)
+    }
+
+    /// Turn every pixel on regardless of the contents of display RAM (`true`), or go back to
+    /// showing the RAM contents (`false`). Useful as a self test to find dead segments.
+    pub fn set_entire_on(&mut self, on: bool) -> Result<(), DI::Error> {
+        Command::AllOn(on).send(self.iface())
+    }
+
+    /// Get the configured addressing mode
+    pub fn get_address_mode(&self) -> AddressMode {
+        self.address_mode
+    }
+
+    /// Set the addressing mode used by the next call to `init`
+    pub fn set_address_mode(&mut self, address_mode: AddressMode) {
+        self.address_mode = address_mode;
+    }
+
+    /// Get the number of 8 pixel tall pages on the configured display, e.g. 8 for a 128x64
+    /// display or 4 for a 128x32 display
+    pub fn page_count(&self) -> u8 {
+        let (_, display_height) = self.display_size.dimensions();
+
+        display_height / 8
     }
 
     /// Get the configured display size
@@ -124,28 +669,13 @@ where
     /// Get display dimensions, taking into account the current rotation of the display
     ///
     /// ```rust
-    /// # struct FakeInterface;
-    /// #
-    /// # impl DisplayInterface for FakeInterface {
-    /// #     fn send_command(&mut self, cmd: u8) -> Result<(), ()> { Ok(()) }
-    /// #     fn send_data(&mut self, buf: &[u8]) -> Result<(), ()> { Ok(()) }
-    /// # }
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
     /// #
-    /// # let interface = FakeInterface {};
-    /// #
-    /// let disp = DisplayProperties::new(
-    ///     interface,
-    ///     DisplaySize::Display128x64,
-    ///     DisplayRotation::Rotate0
-    /// );
+    /// let disp = mock::properties(DisplaySize::Display128x64, DisplayRotation::Rotate0);
     /// assert_eq!(disp.get_dimensions(), (128, 64));
     ///
-    /// # let interface = FakeInterface {};
-    /// let rotated_disp = DisplayProperties::new(
-    ///     interface,
-    ///     DisplaySize::Display128x64,
-    ///     DisplayRotation::Rotate90
-    /// );
+    /// let rotated_disp = mock::properties(DisplaySize::Display128x64, DisplayRotation::Rotate90);
     /// assert_eq!(rotated_disp.get_dimensions(), (64, 128));
     /// ```
     pub fn get_dimensions(&self) -> (u8, u8) {
@@ -162,27 +692,229 @@ where
         self.display_rotation
     }
 
+    /// Set the display rotation used for coordinate mapping without sending any commands to the
+    /// display. Use this if you configure the segment remap and COM scan direction yourself, for
+    /// example on modules where the remap commands misbehave. Otherwise use
+    /// [`set_rotation`](#method.set_rotation), which also updates the panel.
+    pub fn set_rotation_software_only(&mut self, display_rotation: DisplayRotation) {
+        self.display_rotation = display_rotation;
+    }
+
     /// Set the display rotation
     pub fn set_rotation(&mut self, display_rotation: DisplayRotation) -> Result<(), DI::Error> {
         self.display_rotation = display_rotation;
 
-        match display_rotation {
-            DisplayRotation::Rotate0 => {
-                Command::SegmentRemap(true).send(&mut self.iface)?;
-                Command::ReverseComDir(true).send(&mut self.iface)
-            }
-            DisplayRotation::Rotate90 => {
-                Command::SegmentRemap(false).send(&mut self.iface)?;
-                Command::ReverseComDir(true).send(&mut self.iface)
-            }
-            DisplayRotation::Rotate180 => {
-                Command::SegmentRemap(false).send(&mut self.iface)?;
-                Command::ReverseComDir(false).send(&mut self.iface)
-            }
-            DisplayRotation::Rotate270 => {
-                Command::SegmentRemap(true).send(&mut self.iface)?;
-                Command::ReverseComDir(false).send(&mut self.iface)
-            }
+        let (remap, reverse) = self.rotation_commands();
+
+        remap.send(self.iface())?;
+        reverse.send(self.iface())
+    }
+
+    /// Get the segment remap and COM scan direction commands for the current rotation
+    fn rotation_commands(&self) -> (Command, Command) {
+        let (remap, reverse) = match self.display_rotation {
+            DisplayRotation::Rotate0 => (true, true),
+            DisplayRotation::Rotate90 => (false, true),
+            DisplayRotation::Rotate180 => (false, false),
+            DisplayRotation::Rotate270 => (true, false),
+        };
+
+        // SH1107 panels are mounted the other way round in both directions compared to SH1106
+        // panels
+        let (remap, reverse) = if self.display_size.is_sh1107() {
+            (!remap, !reverse)
+        } else {
+            (remap, reverse)
+        };
+
+        (
+            Command::SegmentRemap(remap),
+            Command::ReverseComDir(reverse),
+        )
+    }
+}
+
+impl<DI, CommE, PinE> DisplayProperties<DI>
+where
+    DI: DisplayInterface<Error = Error<CommE, PinE>>,
+{
+    /// Like [`set_draw_area`](#method.set_draw_area), but first check that the area is valid. The
+    /// top and bottom rows must be multiples of 8 as the display is written a page at a time, and
+    /// the area must fit on the display once the column offset is added. Returns
+    /// `Error::InvalidArea` without touching the display otherwise.
+    pub fn set_draw_area_checked(
+        &mut self,
+        start: (u8, u8),
+        end: (u8, u8),
+    ) -> Result<(), Error<CommE, PinE>> {
+        let (display_width, display_height) = self.display_size.dimensions();
+        let column_offset = self.display_size.column_offset();
+
+        let rows_valid = start.1.is_multiple_of(8)
+            && end.1.is_multiple_of(8)
+            && start.1 < end.1
+            && end.1 <= display_height;
+        let columns_valid = start.0 >= column_offset
+            && start.0 < end.0
+            && u16::from(end.0) <= u16::from(display_width) + u16::from(column_offset);
+
+        if !rows_valid || !columns_valid {
+            return Err(Error::InvalidArea);
+        }
+
+        self.set_draw_area(start, end)
+    }
+
+    /// Point the display RAM write pointer at the page containing screen row `y` and screen column
+    /// `x`, adding the column offset. Rotation is not applied. Data sent next is written from
+    /// there onwards; `write_data_at` does this positioning by itself. Returns
+    /// `Error::InvalidArea` without touching the display if the coordinate is off the display.
+    pub fn goto(&mut self, x: u8, y: u8) -> Result<(), Error<CommE, PinE>> {
+        let (display_width, display_height) = self.display_size.dimensions();
+
+        if x >= display_width || y >= display_height {
+            return Err(Error::InvalidArea);
+        }
+
+        self.send_page_address(y / 8)?;
+        self.set_column_address(self.display_size.column_offset() + x)
+    }
+
+    /// Set the display RAM page the next data byte is written to. Page `n` covers rows `n * 8` to
+    /// `n * 8 + 7`. Like `set_column_address`, this doesn't update the draw area. Returns
+    /// `Error::InvalidArea` without touching the display if `page` is past the bottom of the
+    /// display.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// # use sh1106::Error;
+    /// #
+    /// let mut properties = mock::properties(DisplaySize::Display128x32, DisplayRotation::Rotate0);
+    ///
+    /// assert!(properties.set_page_address(3).is_ok());
+    /// // A 128x32 display only has 4 pages
+    /// assert!(matches!(properties.set_page_address(4), Err(Error::InvalidArea)));
+    /// assert!(matches!(properties.set_page_address(255), Err(Error::InvalidArea)));
+    /// ```
+    pub fn set_page_address(&mut self, page: u8) -> Result<(), Error<CommE, PinE>> {
+        if page >= self.page_count() {
+            return Err(Error::InvalidArea);
+        }
+
+        self.send_page_address(page)
+    }
+
+    /// Write `data` straight to display RAM at `page`, starting at screen column `col`, without
+    /// going through a framebuffer. Each byte is one column of 8 pixels, least significant bit at
+    /// the top. The panel's column offset is added for you. Returns `Error::InvalidArea` without
+    /// touching the display if `page` is past the bottom of the display or the data doesn't fit
+    /// between `col` and the right edge.
+    pub fn write_data_at(
+        &mut self,
+        page: u8,
+        col: u8,
+        data: &[u8],
+    ) -> Result<(), Error<CommE, PinE>> {
+        let (display_width, _) = self.display_size.dimensions();
+        let column_offset = self.display_size.column_offset();
+
+        if page >= self.page_count() || col as usize + data.len() > display_width as usize {
+            return Err(Error::InvalidArea);
+        }
+
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let start = column_offset + col;
+        let row = page * 8;
+
+        self.set_draw_area((start, row), (start + data.len() as u8, row + 8))?;
+        self.draw(data)
+    }
+}
+
+impl<DI> DisplayProperties<DI>
+where
+    DI: ReadableInterface,
+{
+    /// Update part of a page of display RAM in place using the controller's read-modify-write
+    /// mode. For each RAM column in `columns` of `page`, the byte currently in display RAM is read
+    /// back and `f` is called with the column and that byte; its return value is written back in
+    /// the same place. The column address is restored when done.
+    ///
+    /// This avoids keeping a framebuffer for small partial updates, but it needs an interface
+    /// that can read, see [`ReadableInterface`](../interface/trait.ReadableInterface.html). As
+    /// with `set_column_address`, columns are RAM columns and include the panel's column offset.
+    /// Nothing is done if `page` is past the bottom of the display.
+    ///
+    /// ```rust
+    /// # use sh1106::mock::MockRamInterface;
+    /// # use sh1106::prelude::*;
+    /// # use sh1106::properties::DisplayProperties;
+    /// #
+    /// let mut iface = MockRamInterface::new();
+    /// iface.page_mut(2)[10..14].copy_from_slice(&[1, 2, 3, 4]);
+    ///
+    /// let mut properties =
+    ///     DisplayProperties::new(iface, DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    /// let mut seen = Vec::new();
+    ///
+    /// properties
+    ///     .rmw_update(2, 10..13, |column, byte| {
+    ///         seen.push((column, byte));
+    ///         byte | 0x80
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let iface = properties.release();
+    /// // Page 2, column 10, enter read-modify-write mode, then leave it
+    /// assert_eq!(iface.commands(), [0xB2, 0x0A, 0x10, 0xE0, 0xEE]);
+    /// // Each column is read past the dummy read and written back in place
+    /// assert_eq!(seen, [(10, 1), (11, 2), (12, 3)]);
+    /// assert_eq!(iface.page(2)[9..15], [0, 0x81, 0x82, 0x83, 4, 0]);
+    /// ```
+    pub fn rmw_update<F>(
+        &mut self,
+        page: u8,
+        columns: core::ops::Range<u8>,
+        mut f: F,
+    ) -> Result<(), DI::Error>
+    where
+        F: FnMut(u8, u8) -> u8,
+    {
+        if columns.start >= columns.end || page >= self.page_count() {
+            return Ok(());
+        }
+
+        self.send_page_address(page)?;
+        self.set_column_address(columns.start)?;
+        Command::ReadModifyWrite.send(self.iface())?;
+
+        // The first read after setting the address returns stale data from the output latch
+        let mut byte = [0];
+        self.iface().read_data(&mut byte)?;
+
+        for column in columns {
+            self.iface().read_data(&mut byte)?;
+            byte[0] = f(column, byte[0]);
+            self.iface().send_data(&byte)?;
+        }
+
+        Command::End.send(self.iface())
+    }
+}
+
+impl<DI> Drop for DisplayProperties<DI>
+where
+    DI: DisplayInterface,
+{
+    fn drop(&mut self) {
+        if self.clear_on_drop && self.iface.is_some() {
+            // Errors can't be returned from `drop()`, so the best we can do is ignore them
+            let _ = self.clear_ram();
+            let _ = Command::DisplayOn(false).send(self.iface());
         }
     }
 }
@@ -0,0 +1,65 @@
+//! Named page ranges of the display
+
+/// A band of whole pages of the display, from `start_page` to `end_page` inclusive, e.g. a gauge
+/// at the top of the screen and a log below it. Regions are cheap to copy, so declare them once
+/// as constants and flush each with
+/// [`GraphicsMode::flush_region`](../mode/graphics/struct.GraphicsMode.html#method.flush_region)
+/// when its part of the screen changes.
+///
+/// ```rust
+/// # use sh1106::region::Region;
+/// const GAUGE: Region = Region::new(0, 1);
+/// const LOG: Region = Region::new(2, 7);
+///
+/// assert_eq!(GAUGE.page_count(), 2);
+/// assert_eq!(LOG.rows(), (16, 64));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Region {
+    start_page: u8,
+    end_page: u8,
+}
+
+impl Region {
+    /// Create a region covering pages `start_page` to `end_page`, both included. Whether the
+    /// pages exist is checked when the region is flushed, as it depends on the display size.
+    pub const fn new(start_page: u8, end_page: u8) -> Self {
+        Self {
+            start_page,
+            end_page,
+        }
+    }
+
+    /// Get the first page of the region
+    pub const fn start_page(self) -> u8 {
+        self.start_page
+    }
+
+    /// Get the last page of the region
+    pub const fn end_page(self) -> u8 {
+        self.end_page
+    }
+
+    /// Get the number of pages the region covers, 0 if the end page is before the start page
+    pub const fn page_count(self) -> u16 {
+        if self.end_page < self.start_page {
+            0
+        } else {
+            self.end_page as u16 - self.start_page as u16 + 1
+        }
+    }
+
+    /// Get the rows of display RAM the region covers as `(first, end)`, with `end` not included.
+    /// These match drawing rows unless the display is rotated by 90 or 270 degrees.
+    pub const fn rows(self) -> (u16, u16) {
+        (
+            self.start_page as u16 * 8,
+            (self.start_page as u16 + self.page_count()) * 8,
+        )
+    }
+
+    /// Whether the region is non-empty and fits on a display with `page_count` pages
+    pub const fn fits(self, page_count: u8) -> bool {
+        self.start_page <= self.end_page && self.end_page < page_count
+    }
+}
@@ -0,0 +1,119 @@
+//! Share a display between interrupt handlers and the main loop
+//!
+//! All [`GraphicsMode`](../mode/graphics/struct.GraphicsMode.html) methods take `&mut self`, so
+//! drawing and flushing can never overlap as long as the display has a single owner. Firmware
+//! that renders in an interrupt handler and flushes from the main loop has two owners, and a
+//! [`SharedDisplay`](struct.SharedDisplay.html) puts the display behind a
+//! [critical section](https://docs.rs/critical-section) mutex for them:
+//!
+//! * [`lock`](struct.SharedDisplay.html#method.lock) runs a closure with exclusive access to the
+//!   display, e.g. to draw from an interrupt handler.
+//! * [`flush`](struct.SharedDisplay.html#method.flush) sends the changed pages to the display,
+//!   one page per critical section. Interrupts are only held off for the time it takes to send a
+//!   single page, and each page is a consistent snapshot, but a frame drawn while a flush is under
+//!   way may show up half in this flush and half in the next.
+//!
+//! This module needs the `critical-section` feature, and the application must link a
+//! critical section implementation, e.g. the one `cortex-m` provides for single core chips.
+//!
+//! ```rust,ignore
+//! static DISPLAY: SharedDisplay<OledInterface> = SharedDisplay::new();
+//!
+//! #[interrupt]
+//! fn TIM2() {
+//!     let reading = read_sensor();
+//!
+//!     DISPLAY.lock(|disp| disp.draw_bar_graph(&[reading], (0, 0), 1, 64, true));
+//! }
+//!
+//! fn main() -> ! {
+//!     let mut disp: GraphicsMode<_> = Builder::new().connect_i2c(i2c).into();
+//!     disp.init().unwrap();
+//!     DISPLAY.install(disp);
+//!
+//!     loop {
+//!         DISPLAY.flush().unwrap();
+//!     }
+//! }
+//! ```
+
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::{GraphicsMode, BUFFER_SIZE};
+
+/// A display that can be drawn to and flushed from different execution contexts
+pub struct SharedDisplay<DI, BUF = [u8; BUFFER_SIZE]>
+where
+    DI: DisplayInterface,
+{
+    display: Mutex<RefCell<Option<GraphicsMode<DI, BUF>>>>,
+}
+
+impl<DI, BUF> SharedDisplay<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Create an empty shared display, e.g. for a `static`. Call
+    /// [`install`](#method.install) to put a display into it.
+    pub const fn new() -> Self {
+        Self {
+            display: Mutex::new(RefCell::new(None)),
+        }
+    }
+
+    /// Put `display` into the shared slot, giving back the display that was there before
+    pub fn install(&self, display: GraphicsMode<DI, BUF>) -> Option<GraphicsMode<DI, BUF>> {
+        critical_section::with(|cs| self.display.borrow_ref_mut(cs).replace(display))
+    }
+
+    /// Take the display back out of the shared slot
+    pub fn take(&self) -> Option<GraphicsMode<DI, BUF>> {
+        critical_section::with(|cs| self.display.borrow_ref_mut(cs).take())
+    }
+
+    /// Run `f` with exclusive access to the display inside a critical section and return its
+    /// result, or `None` if no display is installed. Keep `f` short: interrupts are held off
+    /// until it returns, so avoid calling `flush` from inside it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from inside another `lock` closure on the same display.
+    pub fn lock<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut GraphicsMode<DI, BUF>) -> R,
+    {
+        critical_section::with(|cs| self.display.borrow_ref_mut(cs).as_mut().map(f))
+    }
+
+    /// Send every page that changed since the last flush to the display, each page in its own
+    /// critical section. Does nothing if no display is installed. Like
+    /// [`GraphicsMode::flush`](../mode/graphics/struct.GraphicsMode.html#method.flush), the
+    /// display is pointed back at the start of the frame afterwards, even if sending a page fails.
+    pub fn flush(&self) -> Result<(), DI::Error> {
+        let page_count = self.lock(|disp| disp.page_count()).unwrap_or(0);
+
+        for page in 0..page_count {
+            if let Some(Err(e)) = self.lock(|disp| disp.flush_dirty_page(page)) {
+                let _ = self.lock(|disp| disp.reset_pointer());
+
+                return Err(e);
+            }
+        }
+
+        self.lock(|disp| disp.reset_pointer()).unwrap_or(Ok(()))
+    }
+}
+
+impl<DI, BUF> Default for SharedDisplay<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,86 @@
+//! Text layout helpers
+//!
+//! Text is drawn with a fixed width 6x8 pixel font, the same metrics as
+//! [embedded_graphics](../../embedded_graphics/index.html)'s `Font6x8`. These helpers don't need
+//! the `graphics` feature, so they can be used for layout in any mode.
+//!
+//! Text functions take `&str`, so strings built without an allocator, such as `heapless::String`,
+//! which dereferences to `&str`, can be passed as they are. No wrapper or extra feature is needed.
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+/// Horizontal advance of each character in pixels
+pub const CHAR_WIDTH: u32 = 6;
+
+/// Height of each line of text in pixels
+pub const CHAR_HEIGHT: u32 = 8;
+
+/// Get the width in pixels of `s` when drawn with the 6x8 font. For strings containing newlines,
+/// the width of the widest line is returned.
+///
+/// ```rust
+/// # use sh1106::text::text_width;
+/// assert_eq!(text_width("Hello"), 30);
+/// assert_eq!(text_width("Hi\nthere"), 30);
+/// assert_eq!(text_width(""), 0);
+/// ```
+pub fn text_width(s: &str) -> u32 {
+    s.split('\n')
+        .map(|line| line.chars().count() as u32 * CHAR_WIDTH)
+        .max()
+        .unwrap_or(0)
+}
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Get the size of the character grid as `(columns, rows)`, i.e. how many whole 6x8 character
+    /// cells fit on the display in its current rotation
+    pub fn text_grid(&self) -> (u32, u32) {
+        let (width, height) = self.get_dimensions();
+
+        (
+            u32::from(width) / CHAR_WIDTH,
+            u32::from(height) / CHAR_HEIGHT,
+        )
+    }
+
+    /// Clear the 6x8 character cell at `col, row` of the character grid, leaving the rest of the
+    /// line untouched. Cell `0, 0` is in the top left corner, the same grid `draw_text` uses when
+    /// drawn at a multiple of the character size. Cells outside the grid are ignored.
+    pub fn clear_cell(&mut self, col: u32, row: u32) {
+        let (columns, rows) = self.text_grid();
+
+        if col >= columns || row >= rows {
+            return;
+        }
+
+        let (x, y) = (col * CHAR_WIDTH, row * CHAR_HEIGHT);
+
+        for line in y..y + CHAR_HEIGHT {
+            self.draw_pixel_run(x, line, CHAR_WIDTH, false);
+        }
+    }
+
+    /// Invert every pixel of the 6x8 character cell at `col, row` of the character grid.
+    /// Inverting the same cell again restores it, so calling this on a timer makes a blinking
+    /// cursor. Cells outside the grid are ignored.
+    pub fn invert_cell(&mut self, col: u32, row: u32) {
+        let (columns, rows) = self.text_grid();
+
+        if col >= columns || row >= rows {
+            return;
+        }
+
+        let (x, y) = (col * CHAR_WIDTH, row * CHAR_HEIGHT);
+
+        for py in y..y + CHAR_HEIGHT {
+            for px in x..x + CHAR_WIDTH {
+                self.toggle_pixel(px, py);
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Circular arcs and pie slices
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+/// `sin(d) * 1024` for whole degrees from 0 to 90
+const SINE: [i16; 91] = [
+    0, 18, 36, 54, 71, 89, 107, 125, 143, 160, 178, 195, 213, 230, 248, 265, 282, 299, 316, 333,
+    350, 367, 384, 400, 416, 433, 449, 465, 481, 496, 512, 527, 543, 558, 573, 587, 602, 616, 630,
+    644, 658, 672, 685, 698, 711, 724, 737, 749, 761, 773, 784, 796, 807, 818, 828, 839, 849, 859,
+    868, 878, 887, 896, 904, 912, 920, 928, 935, 943, 949, 956, 962, 968, 974, 979, 984, 989, 994,
+    998, 1002, 1005, 1008, 1011, 1014, 1016, 1018, 1020, 1022, 1023, 1023, 1024, 1024,
+];
+
+/// `sin(degrees) * 1024`, for any angle
+fn sin_deg(degrees: u32) -> i64 {
+    let degrees = degrees % 360;
+    let quadrant_angle = (degrees % 180) as usize;
+    let value = i64::from(SINE[quadrant_angle.min(180 - quadrant_angle)]);
+
+    if degrees < 180 {
+        value
+    } else {
+        -value
+    }
+}
+
+/// Unit vector scaled by 1024 pointing at `degrees` clockwise from the positive X axis, with Y
+/// pointing down like display coordinates
+fn direction(degrees: u32) -> (i64, i64) {
+    let degrees = degrees % 360;
+
+    (sin_deg(degrees + 90), sin_deg(degrees))
+}
+
+/// Z component of the cross product of `a` and `b`, positive when `b` is clockwise from `a`
+fn cross(a: (i64, i64), b: (i64, i64)) -> i64 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Draw the part of a circle around `center` between two angles, e.g. for a radial gauge.
+    /// `angles` is `(start, end)` in degrees, with 0 pointing right and angles increasing
+    /// clockwise, so 90 points down; the arc runs clockwise from `start` to `end`, wrapping
+    /// through 0 if `end` is smaller. An `end` 360 or more degrees past `start` draws the whole
+    /// circle. When `filled` is set, the pie slice between the arc and the center is filled as
+    /// well. Pixels are set or cleared according to `on`.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    ///
+    /// // The bottom right quarter of a circle
+    /// disp.draw_arc((20, 20), 10, (0, 90), false, true);
+    /// assert!(disp.get_pixel(30, 20) && disp.get_pixel(20, 30) && disp.get_pixel(27, 27));
+    /// assert!(!disp.get_pixel(10, 20) && !disp.get_pixel(20, 10) && !disp.get_pixel(25, 25));
+    ///
+    /// // Filling the same slice covers the inside too
+    /// disp.draw_arc((20, 20), 10, (0, 90), true, true);
+    /// assert!(disp.get_pixel(25, 25) && !disp.get_pixel(15, 15));
+    ///
+    /// // Any angles and radii work, only the part of the arc on the display is visited
+    /// disp.clear();
+    /// disp.draw_arc((64, 32), u32::MAX, (u32::MAX - 10, 20), true, true);
+    /// assert!(disp.get_pixel(127, 45) && !disp.get_pixel(127, 63) && !disp.get_pixel(0, 0));
+    /// ```
+    pub fn draw_arc(
+        &mut self,
+        center: (u32, u32),
+        radius: u32,
+        angles: (u32, u32),
+        filled: bool,
+        on: bool,
+    ) {
+        let (start, end) = angles;
+        let value = on as u8;
+
+        let full_circle = end.saturating_sub(start) >= 360;
+        let sweep = (end % 360 + 360 - start % 360) % 360;
+        let from = direction(start);
+        let to = direction(start % 360 + sweep);
+
+        let in_sweep = |point: (i64, i64)| {
+            if full_circle {
+                true
+            } else if sweep <= 180 {
+                cross(from, point) >= 0 && cross(point, to) >= 0
+            } else {
+                // Outside the arc is the sweep of less than half a turn from `to` to `from`
+                !(cross(to, point) > 0 && cross(point, from) > 0)
+            }
+        };
+
+        let (cx, cy) = (i64::from(center.0), i64::from(center.1));
+        let r = i64::from(radius);
+        // Pixels whose centers are within half a pixel of the circle, compared at twice the scale
+        let inner = i128::from((2 * r - 1).max(0)).pow(2);
+        let outer = i128::from(2 * r + 1).pow(2);
+
+        // Only visit the part of the bounding box that is on the display
+        let (width, height) = self.get_dimensions();
+        let (left, right) = ((-r).max(-cx), r.min(i64::from(width) - 1 - cx));
+        let (top, bottom) = ((-r).max(-cy), r.min(i64::from(height) - 1 - cy));
+
+        for dy in top..=bottom {
+            for dx in left..=right {
+                let distance = 4 * (i128::from(dx).pow(2) + i128::from(dy).pow(2));
+
+                if distance >= outer || (!filled && distance < inner) {
+                    continue;
+                }
+
+                if in_sweep((dx, dy)) {
+                    self.set_pixel((cx + dx) as u32, (cy + dy) as u32, value);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Bar graphs of sampled values
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Draw `samples` as a graph of vertical bars `height` pixels tall with its top left corner at
+    /// `top_left`, e.g. the recent history of a sensor reading. Each sample gets a bar
+    /// `bar_width` pixels wide, growing up from the bottom edge of the graph, with `0` drawing
+    /// nothing and `255` the full height. Bars are set or cleared according to `on`; the rest of
+    /// the graph area is left untouched. Samples that don't fit on the display are skipped.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    /// disp.draw_bar_graph(&[0, 128, 255], (10, 0), 2, 20, true);
+    ///
+    /// // No bar for the first sample, half height for the second, full height for the third
+    /// assert!(!disp.get_pixel(10, 19));
+    /// assert!(disp.get_pixel(13, 10) && !disp.get_pixel(13, 9));
+    /// assert!(disp.get_pixel(15, 0) && disp.get_pixel(15, 19));
+    /// assert!(!disp.get_pixel(15, 20) && !disp.get_pixel(16, 0));
+    ///
+    /// // Graphs taller than the display are clipped
+    /// disp.draw_bar_graph(&[255], (0, 0), 1, u32::MAX, true);
+    /// assert!(disp.get_pixel(0, 0) && disp.get_pixel(0, 63));
+    /// ```
+    pub fn draw_bar_graph(
+        &mut self,
+        samples: &[u8],
+        top_left: (u32, u32),
+        bar_width: u32,
+        height: u32,
+        on: bool,
+    ) {
+        let (x, y) = top_left;
+        let (width, _) = self.get_dimensions();
+
+        if bar_width == 0 {
+            return;
+        }
+
+        for (i, sample) in samples.iter().enumerate() {
+            let left = x.saturating_add((i as u32).saturating_mul(bar_width));
+
+            if left >= u32::from(width) {
+                break;
+            }
+
+            // Round to the nearest pixel so small samples still show up
+            let bar = ((u64::from(*sample) * u64::from(height) + 127) / 255) as u32;
+
+            self.fill_area(left, y.saturating_add(height - bar), bar_width, bar, on);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Text scrolling sideways across a line of the display
+
+use core::convert::TryFrom;
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+use crate::text::{text_width, CHAR_HEIGHT, CHAR_WIDTH};
+
+/// A single line of text that scrolls to the left one pixel per [`tick`](#method.tick), for
+/// tickers and "now playing" displays. The text wraps around with a gap between its end and the
+/// next repetition, so it scrolls forever. Scrolling is done in the buffer with pixel steps,
+/// unlike the page sized steps hardware scrolling on similar controllers would give.
+///
+/// The marquee only borrows its text and keeps the scroll position, so it needs no allocator.
+///
+/// ```rust
+/// # use sh1106::mock;
+/// # use sh1106::prelude::*;
+/// #
+/// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+///
+/// // Gaps too long to add up to a period saturate instead of overflowing
+/// let mut marquee = Marquee::new("hi").with_gap(u32::MAX);
+/// assert_eq!(marquee.period(), u32::MAX);
+///
+/// marquee.tick();
+/// assert_eq!(marquee.offset(), 1);
+///
+/// // The text is one pixel off the left edge, and its repetition far off the right
+/// marquee.draw(&mut disp, 0, true);
+/// assert!(disp.pixels().any(|(x, _, on)| on && x < 11));
+/// assert!(!disp.pixels().any(|(x, _, on)| on && x >= 11));
+/// ```
+///
+/// ```rust,ignore
+/// let mut marquee = Marquee::new("Now playing: Rust - Zero Cost Abstractions");
+///
+/// loop {
+///     marquee.draw(&mut disp, 24, true);
+///     disp.flush().unwrap();
+///     marquee.tick();
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Marquee<'a> {
+    text: &'a str,
+    gap: u32,
+    offset: u32,
+}
+
+impl<'a> Marquee<'a> {
+    /// Create a marquee scrolling `text`, with a gap of three characters before it repeats. The
+    /// text is drawn on a single line, so it shouldn't contain newlines.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            gap: 3 * CHAR_WIDTH,
+            offset: 0,
+        }
+    }
+
+    /// Set the gap between the end of the text and the start of its next repetition, in pixels
+    pub fn with_gap(self, gap: u32) -> Self {
+        Self {
+            gap,
+            offset: self.offset % text_width(self.text).saturating_add(gap).max(1),
+            ..self
+        }
+    }
+
+    /// Get the scrolling text
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Get how many pixels the text has scrolled to the left, from 0 up to but not including
+    /// [`period`](#method.period)
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Get the number of pixels the text scrolls before the marquee looks the same again: the
+    /// width of the text plus the gap
+    pub fn period(&self) -> u32 {
+        text_width(self.text).saturating_add(self.gap)
+    }
+
+    /// Scroll the text one pixel to the left, wrapping around after a whole period
+    pub fn tick(&mut self) {
+        let period = self.period();
+
+        if period > 0 {
+            self.offset = self.offset.saturating_add(1) % period;
+        }
+    }
+
+    /// Go back to the start of the text
+    pub fn reset(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Draw the marquee on the 8 pixel tall line starting at `y`, across the whole display
+    /// width. The line is cleared to the opposite of `on` before the text is drawn with `on`
+    /// pixels, so the marquee can be redrawn every tick without clearing the buffer first.
+    pub fn draw<DI, BUF>(&self, disp: &mut GraphicsMode<DI, BUF>, y: u32, on: bool)
+    where
+        DI: DisplayInterface,
+        BUF: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        let (width, _) = disp.get_dimensions();
+        let width = u32::from(width);
+
+        for line in y..y.saturating_add(CHAR_HEIGHT) {
+            disp.draw_pixel_run(0, line, width, !on);
+        }
+
+        let period = i64::from(self.period());
+
+        if period == 0 {
+            return;
+        }
+
+        // Repeat the text until the line is covered; the copy scrolled off the left is clipped
+        let mut x = -i64::from(self.offset);
+
+        if x + i64::from(text_width(self.text)) <= 0 {
+            x += period;
+        }
+
+        let y = i32::try_from(y).unwrap_or(i32::MAX);
+
+        while x < i64::from(width) {
+            disp.draw_text(self.text, x as i32, y, on);
+            x += period;
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Scrolling list of menu items
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+use crate::text::CHAR_HEIGHT;
+
+/// A vertical list of text items, one per 8 pixel line, with the selected item drawn inverted.
+/// When there are more items than fit on the display, the list scrolls to keep the selected item
+/// visible.
+///
+/// The menu doesn't own any state besides the items, so the application keeps track of the
+/// selected index and passes it to [`draw`](#method.draw) after each button press.
+///
+/// ```rust,ignore
+/// let menu = Menu::new(&["Contrast", "Rotation", "Sleep", "About"]);
+///
+/// disp.clear();
+/// menu.draw(&mut disp, selected);
+/// disp.flush().unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Menu<'a> {
+    items: &'a [&'a str],
+}
+
+impl<'a> Menu<'a> {
+    /// Create a menu showing `items` from top to bottom
+    pub fn new(items: &'a [&'a str]) -> Self {
+        Self { items }
+    }
+
+    /// Get the items shown in the menu
+    pub fn items(&self) -> &'a [&'a str] {
+        self.items
+    }
+
+    /// Get the index of the first item shown when `selected` is highlighted on a display with
+    /// room for `rows` lines
+    pub fn first_visible(&self, selected: usize, rows: usize) -> usize {
+        let last_page = self.items.len().saturating_sub(rows);
+
+        selected
+            .saturating_sub(rows.saturating_sub(1))
+            .min(last_page)
+    }
+
+    /// Draw the menu over the whole display with item `selected` highlighted. Each line is
+    /// cleared before its item is drawn, so the menu can be redrawn without clearing the buffer
+    /// first. An out of range `selected` highlights nothing.
+    pub fn draw<DI, BUF>(&self, disp: &mut GraphicsMode<DI, BUF>, selected: usize)
+    where
+        DI: DisplayInterface,
+        BUF: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        let (width, height) = disp.get_dimensions();
+        let (width, height) = (u32::from(width), u32::from(height));
+        let rows = (height / CHAR_HEIGHT) as usize;
+        let first = self.first_visible(selected, rows);
+
+        for row in 0..rows {
+            let y = row as u32 * CHAR_HEIGHT;
+            let highlighted = first + row == selected;
+
+            for line in y..y + CHAR_HEIGHT {
+                disp.draw_pixel_run(0, line, width, highlighted);
+            }
+
+            if let Some(item) = self.items.get(first + row) {
+                disp.draw_text(item, 0, y as i32, !highlighted);
+            }
+        }
+    }
+}
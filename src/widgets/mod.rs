@@ -0,0 +1,25 @@
+//! Small UI building blocks drawn straight into the
+//! [`GraphicsMode`](../mode/graphics/struct.GraphicsMode.html) buffer
+//!
+//! These are only compiled with the `widgets` feature, so minimal builds don't pay for them. Only
+//! [`Menu`](struct.Menu.html) and [`Marquee`](struct.Marquee.html) draw text, so they also need the
+//! `graphics` feature for their font.
+//! Like the rest of the drawing methods, widgets respect the display rotation and clip anything
+//! that falls outside the display.
+
+mod arc;
+mod bar_graph;
+#[cfg(feature = "graphics")]
+mod marquee;
+#[cfg(feature = "graphics")]
+mod menu;
+mod rounded_rect;
+mod separator;
+mod seven_segment;
+mod triangle;
+
+#[cfg(feature = "graphics")]
+pub use self::marquee::Marquee;
+#[cfg(feature = "graphics")]
+pub use self::menu::Menu;
+pub use self::seven_segment::seven_segment_size;
@@ -0,0 +1,89 @@
+//! Rectangles with rounded corners
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+/// How far row `row` of a `height` pixel tall rectangle is indented from each side by corners of
+/// the given radius
+fn corner_inset(row: u32, height: u32, radius: u32) -> u32 {
+    // Rows in the bottom half mirror the top half
+    let row = row.min(height - 1 - row);
+
+    if row >= radius {
+        return 0;
+    }
+
+    let (radius, dy) = (u64::from(radius), u64::from(radius - row));
+
+    (radius - (radius * radius - dy * dy).isqrt()) as u32
+}
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Draw a rectangle `size` pixels `(width, height)` big with its top left corner at
+    /// `top_left` and corners rounded off with the given `radius`. When `filled` is set the whole
+    /// shape is set or cleared according to `on`, otherwise only its one pixel wide outline. The
+    /// radius is limited to half the shorter side; a radius of zero draws square corners. Only
+    /// the rows on the display are visited, so rectangles reaching far off screen are fine.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    ///
+    /// disp.draw_rounded_rect((0, 0), (10, 10), 3, true, true);
+    /// assert!(!disp.get_pixel(0, 0) && disp.get_pixel(3, 0) && disp.get_pixel(0, 3));
+    /// assert!(disp.get_pixel(5, 5) && !disp.get_pixel(9, 9) && !disp.get_pixel(10, 5));
+    ///
+    /// // Huge rectangles are clipped to the display. The curve of this corner is off screen.
+    /// disp.clear();
+    /// disp.draw_rounded_rect((0, 0), (200_000, 200_000), 100_000, false, true);
+    /// assert_eq!(disp.lit_pixel_ratio(), 0.0);
+    ///
+    /// disp.draw_rounded_rect((0, 10), (u32::MAX, u32::MAX), 5, true, true);
+    /// assert!(!disp.get_pixel(0, 10) && disp.get_pixel(127, 10) && disp.get_pixel(0, 63));
+    /// ```
+    pub fn draw_rounded_rect(
+        &mut self,
+        top_left: (u32, u32),
+        size: (u32, u32),
+        radius: u32,
+        filled: bool,
+        on: bool,
+    ) {
+        let ((x, y), (width, height)) = (top_left, size);
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let radius = radius.min(width / 2).min(height / 2);
+        let (_, display_height) = self.get_dimensions();
+        let visible_rows = height.min(u32::from(display_height).saturating_sub(y));
+
+        for row in 0..visible_rows {
+            let inset = corner_inset(row, height, radius);
+            let span = width - 2 * inset;
+            let left = x.saturating_add(inset);
+            let py = y.saturating_add(row);
+
+            if filled || row == 0 || row == height - 1 {
+                self.draw_pixel_run(left, py, span, on);
+                continue;
+            }
+
+            // Join up with the row nearer the top or bottom edge, where the corner is steep
+            let outer = if row < height / 2 { row - 1 } else { row + 1 };
+            let run = (corner_inset(outer, height, radius) - inset)
+                .max(1)
+                .min(span);
+
+            self.draw_pixel_run(left, py, run, on);
+            self.draw_pixel_run(left.saturating_add(span - run), py, run, on);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Dotted and dashed separator lines
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Draw a horizontal line `len` pixels long starting at `x, y`, made of dashes `on_pixels`
+    /// long separated by gaps `gap_pixels` long. Dashes are set or cleared according to `on`;
+    /// gaps are left untouched. A `gap_pixels` of zero draws a solid line. Dashes past the right
+    /// edge of the display are skipped.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    ///
+    /// // Dashes 3 pixels long with 2 pixel gaps, the last dash cut short by the length
+    /// disp.draw_dotted_hline(1, 4, 12, 3, 2, true);
+    /// let row: String = (0..16).map(|x| if disp.get_pixel(x, 4) { '#' } else { '.' }).collect();
+    /// assert_eq!(row, ".###..###..##...");
+    ///
+    /// // A huge gap leaves a single dash
+    /// disp.draw_dotted_hline(0, 10, 100, 4, u32::MAX, true);
+    /// assert!(disp.get_pixel(3, 10) && !disp.get_pixel(4, 10));
+    /// ```
+    pub fn draw_dotted_hline(
+        &mut self,
+        x: u32,
+        y: u32,
+        len: u32,
+        on_pixels: u32,
+        gap_pixels: u32,
+        on: bool,
+    ) {
+        if on_pixels == 0 {
+            return;
+        }
+
+        let (width, _) = self.get_dimensions();
+        let end = len.min(u32::from(width).saturating_sub(x));
+        let period = on_pixels.saturating_add(gap_pixels);
+        let mut offset = 0;
+
+        while offset < end {
+            let dash = on_pixels.min(len - offset);
+
+            self.draw_pixel_run(x.saturating_add(offset), y, dash, on);
+
+            offset = offset.saturating_add(period);
+        }
+    }
+}
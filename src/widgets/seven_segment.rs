@@ -0,0 +1,102 @@
+//! Seven segment digits
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+/// Lit segments for the digits 0 to 9, bits 0 to 6 being segments a to g
+const DIGITS: [u8; 10] = [0x3F, 0x06, 0x5B, 0x4F, 0x66, 0x6D, 0x7D, 0x07, 0x7F, 0x6F];
+
+/// Segment g, the middle bar
+const MINUS: u8 = 0x40;
+
+/// Get the `(width, height)` in pixels of a seven segment character drawn at `scale`. The
+/// segments are `scale` pixels thick and `4 * scale` pixels long.
+///
+/// ```rust
+/// # use sh1106::widgets::seven_segment_size;
+/// assert_eq!(seven_segment_size(1), (6, 11));
+/// assert_eq!(seven_segment_size(3), (18, 33));
+/// ```
+pub const fn seven_segment_size(scale: u32) -> (u32, u32) {
+    (scale.saturating_mul(6), scale.saturating_mul(11))
+}
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Draw `c` as a seven segment character with its top left corner at `x, y`. Digits `'0'` to
+    /// `'9'`, `'-'` and `':'` are supported; anything else draws an empty cell. The whole cell,
+    /// as given by [`seven_segment_size`](../widgets/fn.seven_segment_size.html), is cleared
+    /// first so a changing digit can be redrawn in place.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    ///
+    /// // Segment a of an 8 is drawn along the top of the cell
+    /// disp.draw_seven_segment('8', 0, 0, 2);
+    /// assert!(disp.get_pixel(2, 0) && !disp.get_pixel(0, 0));
+    ///
+    /// // Cells partly or entirely off the display are clipped
+    /// disp.draw_seven_segment('8', 124, 0, u32::MAX / 2);
+    /// disp.draw_seven_segment('8', u32::MAX - 3, 0, 2);
+    /// assert!(!disp.get_pixel(127, 0));
+    /// ```
+    pub fn draw_seven_segment(&mut self, c: char, x: u32, y: u32, scale: u32) {
+        if scale == 0 {
+            return;
+        }
+
+        let (display_width, display_height) = self.get_dimensions();
+
+        if x >= u32::from(display_width) || y >= u32::from(display_height) {
+            return;
+        }
+
+        let (width, height) = seven_segment_size(scale);
+        let (t, l) = (scale, scale.saturating_mul(4));
+        let (t2, l2) = (t.saturating_mul(2), l.saturating_mul(2));
+        // Offsets within the cell, which may run past `u32::MAX` for huge scales
+        let at = |dx: u32, dy: u32| (x.saturating_add(dx), y.saturating_add(dy));
+
+        self.fill_area(x, y, width, height, false);
+
+        if c == ':' {
+            let dot_x = (width - t) / 2;
+            let (upper_x, upper_y) = at(dot_x, t.saturating_add(l / 2));
+            let (lower_x, lower_y) = at(dot_x, t2.saturating_add(l.saturating_add(l / 2)));
+
+            self.fill_area(upper_x, upper_y, t, t, true);
+            self.fill_area(lower_x, lower_y, t, t, true);
+
+            return;
+        }
+
+        let segments = match c {
+            '0'..='9' => DIGITS[c as usize - '0' as usize],
+            '-' => MINUS,
+            _ => 0,
+        };
+
+        // Position and size of segments a to g
+        let areas = [
+            (at(t, 0), l, t),
+            (at(t.saturating_add(l), t), t, l),
+            (at(t.saturating_add(l), t2.saturating_add(l)), t, l),
+            (at(t, t2.saturating_add(l2)), l, t),
+            (at(0, t2.saturating_add(l)), t, l),
+            (at(0, t), t, l),
+            (at(t, t.saturating_add(l)), l, t),
+        ];
+
+        for (segment, ((x, y), width, height)) in areas.iter().enumerate() {
+            if segments & (1 << segment) != 0 {
+                self.fill_area(*x, *y, *width, *height, true);
+            }
+        }
+    }
+}
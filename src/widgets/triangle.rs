@@ -0,0 +1,88 @@
+//! Filled triangles
+
+use crate::interface::DisplayInterface;
+use crate::mode::graphics::GraphicsMode;
+
+/// X coordinate, rounded to the nearest pixel, where the edge from `a` to `b` crosses row `y`, or
+/// `None` if it doesn't. Horizontal edges cross their row at both ends, so both are returned.
+/// Coordinates are `i128` so the products can't overflow for any `u32` corner.
+fn edge_span(a: (i128, i128), b: (i128, i128), y: i128) -> Option<(i128, i128)> {
+    let ((x0, y0), (x1, y1)) = if a.1 <= b.1 { (a, b) } else { (b, a) };
+
+    if y < y0 || y > y1 {
+        return None;
+    }
+
+    if y0 == y1 {
+        return Some((x0.min(x1), x0.max(x1)));
+    }
+
+    let dy = y1 - y0;
+    let x = x0 + ((x1 - x0) * (y - y0) * 2 + dy).div_euclid(dy * 2);
+
+    Some((x, x))
+}
+
+impl<DI, BUF> GraphicsMode<DI, BUF>
+where
+    DI: DisplayInterface,
+    BUF: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Fill the triangle with corners `p0`, `p1` and `p2`, e.g. for play buttons and arrows. The
+    /// corners may be given in any order and the edges are included. Pixels are set or cleared
+    /// according to `on`, one horizontal run per row. Only the rows and columns on the display
+    /// are visited, so corners far off screen are fine.
+    ///
+    /// ```rust
+    /// # use sh1106::mock;
+    /// # use sh1106::prelude::*;
+    /// #
+    /// let mut disp = mock::graphics(DisplaySize::Display128x64, DisplayRotation::Rotate0);
+    ///
+    /// // A play button pointing right
+    /// disp.fill_triangle((10, 10), (10, 20), (15, 15), true);
+    /// assert!(disp.get_pixel(10, 10) && disp.get_pixel(10, 20) && disp.get_pixel(15, 15));
+    /// assert!(disp.get_pixel(12, 15) && !disp.get_pixel(16, 15) && !disp.get_pixel(14, 11));
+    /// assert_eq!(disp.pixels().filter(|(_, _, on)| *on).count(), 36);
+    ///
+    /// // Corners far off screen are clipped to the display
+    /// disp.fill_triangle((0, 0), (u32::MAX, 0), (0, u32::MAX), true);
+    /// assert!(disp.pixels().all(|(_, _, on)| on));
+    /// ```
+    pub fn fill_triangle(&mut self, p0: (u32, u32), p1: (u32, u32), p2: (u32, u32), on: bool) {
+        let (width, height) = self.get_dimensions();
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let corners = [p0, p1, p2].map(|(x, y)| (i128::from(x), i128::from(y)));
+        let edges = [
+            (corners[0], corners[1]),
+            (corners[1], corners[2]),
+            (corners[2], corners[0]),
+        ];
+
+        let top = corners.iter().map(|corner| corner.1).min().unwrap_or(0);
+        let bottom = corners.iter().map(|corner| corner.1).max().unwrap_or(0);
+        let last_column = i128::from(width) - 1;
+
+        for y in top..=bottom.min(i128::from(height) - 1) {
+            let span = edges.iter().filter_map(|&(a, b)| edge_span(a, b, y)).fold(
+                None,
+                |span: Option<(i128, i128)>, (left, right)| match span {
+                    Some((min, max)) => Some((min.min(left), max.max(right))),
+                    None => Some((left, right)),
+                },
+            );
+
+            if let Some((left, right)) = span {
+                let right = right.min(last_column);
+
+                if left <= right {
+                    self.draw_pixel_run(left as u32, y as u32, (right - left + 1) as u32, on);
+                }
+            }
+        }
+    }
+}